@@ -1,9 +1,9 @@
 use bcrypt::{hash, DEFAULT_COST};
 use json::object;
-use rand::{distributions::Alphanumeric, Rng};
-use serwer::{route, Cookie, Data, Serwer, StatusCode};
+use rand::Rng;
+use serwer::{route, Cookie, Data, Request, SameSite, Serwer, StatusCode};
 use sqlite::Connection;
-use std::fs;
+use std::{fs, time::Duration};
 
 struct User {
     id: i64,
@@ -11,20 +11,15 @@ struct User {
     password: String,
 }
 
-struct Session {
-    _id: String,
-    user_id: i64,
-}
-
 fn main() {
     let connection = sqlite::Connection::open_thread_safe(":memory:").unwrap();
 
     let mut serwer = Serwer::new();
+    serwer.secret_key(&rand::thread_rng().gen::<[u8; 32]>());
 
     let query = "
         CREATE TABLE users (id INTEGER PRIMARY KEY, username TEXT, password TEXT);
         CREATE TABLE posts (id INTEGER PRIMARY KEY, user_id INTEGER, text TEXT);
-        CREATE TABLE sessions (id INTEGER, user_id INTEGER);
     ";
     connection.execute(query).unwrap();
 
@@ -65,10 +60,7 @@ fn main() {
                 return res;
             }
 
-            remove_session(&connection, user.id);
-            let session_id = generate_session_id();
-            add_session(&connection, &session_id, user.id);
-            res.set_cookie("session_id", Cookie::new("session_id", &session_id).set_max_age(1000 * 60 * 60 * 3));
+            res.set_private_cookie("user_id", Cookie::new("user_id", &user.id.to_string()).set_max_age(Duration::from_secs(60 * 60 * 3)).set_http_only(true).set_same_site(SameSite::Lax));
 
             res.set(StatusCode::OK, (object! {
                 status: "success",
@@ -106,9 +98,7 @@ fn main() {
             add_user(&connection, &data["username"].to_string(), &hashed_password);
 
             let user = get_user_by_username(&connection, &data["username"].to_string()).unwrap();
-            let session_id = generate_session_id();
-            add_session(&connection, &session_id, user.id);
-            res.set_cookie("session_id", Cookie::new("session_id", &session_id).set_max_age(1000 * 60 * 60 * 3));
+            res.set_private_cookie("user_id", Cookie::new("user_id", &user.id.to_string()).set_max_age(Duration::from_secs(60 * 60 * 3)).set_http_only(true).set_same_site(SameSite::Lax));
 
             res.set(StatusCode::Created, (object! {
                 status: "success",
@@ -120,33 +110,16 @@ fn main() {
 
     serwer.get(
         "/signout",
-        route! {(connection) move |req, mut res| {
-            let connection = connection.read();
-
-            let cookie = match req.cookie("session_id") {
-                Some(cookie) => cookie,
-                None => {
-                    res.set(StatusCode::Unauthorized, (object! {
-                        status: "error",
-                        message: "User is not signed in"
-                    }).dump());
-                    return res;
-                }
-            };
-
-            let session = match get_session(&connection, cookie.value()) {
-                Some(session) => session,
-                None => {
-                    res.set(StatusCode::Unauthorized, (object! {
-                        status: "error",
-                        message: "Session not found"
-                    }).dump());
-                    return res;
-                }
-            };
+        route! {() move |req, mut res| {
+            if signed_in_user_id(&req).is_none() {
+                res.set(StatusCode::Unauthorized, (object! {
+                    status: "error",
+                    message: "User is not signed in"
+                }).dump());
+                return res;
+            }
 
-            remove_session(&connection, session.user_id);
-            res.set_cookie("session_id", Cookie::new("session_id", "").set_max_age(0));
+            res.remove_cookie("user_id");
 
             res.set(StatusCode::OK, (object! {
                 status: "success",
@@ -161,8 +134,8 @@ fn main() {
         route! {(connection) move |req, mut res| {
             let connection = connection.read();
 
-            let cookie = match req.cookie("session_id") {
-                Some(cookie) => cookie,
+            let user_id = match signed_in_user_id(&req) {
+                Some(user_id) => user_id,
                 None => {
                     res.set(StatusCode::Unauthorized, (object! {
                         status: "error",
@@ -172,18 +145,7 @@ fn main() {
                 }
             };
 
-            let session = match get_session(&connection, cookie.value()) {
-                Some(session) => session,
-                None => {
-                    res.set(StatusCode::Unauthorized, (object! {
-                        status: "error",
-                        message: "Session not found"
-                    }).dump());
-                    return res;
-                }
-            };
-
-            let user = match get_user_by_id(&connection, session.user_id) {
+            let user = match get_user_by_id(&connection, user_id) {
                 Some(user) => user,
                 None => {
                     res.set(StatusCode::Unauthorized, (object! {
@@ -201,19 +163,8 @@ fn main() {
 
     serwer.get(
         "/",
-        route! {(connection) move |req, mut res| {
-            let connection = connection.read();
-
-            let cookie = match req.cookie("session_id") {
-                Some(cookie) => cookie,
-                None => {
-                    let body = fs::read_to_string("static/index.html").unwrap();
-                    res.set(StatusCode::OK, body);
-                    return res;
-                }
-            };
-
-            if get_session(&connection, cookie.value()).is_none() {
+        route! {() move |req, mut res| {
+            if signed_in_user_id(&req).is_none() {
                 let body = fs::read_to_string("static/index.html").unwrap();
                 res.set(StatusCode::OK, body);
                 return res;
@@ -227,19 +178,8 @@ fn main() {
 
     serwer.get(
         "/home",
-        route! {(connection) move |req, mut res| {
-            let connection = connection.read();
-
-            let cookie = match req.cookie("session_id") {
-                Some(cookie) => cookie,
-                None => {
-                    res.set_status_code(StatusCode::SeeOther);
-                    res.set_header("Location", "/");
-                    return res;
-                }
-            };
-
-            if get_session(&connection, cookie.value()).is_none() {
+        route! {() move |req, mut res| {
+            if signed_in_user_id(&req).is_none() {
                 res.set_status_code(StatusCode::SeeOther);
                 res.set_header("Location", "/");
                 return res;
@@ -306,45 +246,6 @@ fn get_user_by_id(connection: &Connection, id: i64) -> Option<User> {
     }
 }
 
-fn add_session(connection: &Connection, session_id: &str, user_id: i64) {
-    let mut statement = connection
-        .prepare("INSERT INTO sessions (id, user_id) VALUES (?, ?);")
-        .unwrap();
-    statement.bind((1, session_id)).unwrap();
-    statement.bind((2, user_id)).unwrap();
-
-    statement.next().unwrap();
-}
-
-fn get_session(connection: &Connection, session_id: &str) -> Option<Session> {
-    let mut statement = connection
-        .prepare("SELECT * FROM sessions WHERE id = ?;")
-        .unwrap();
-    statement.bind((1, session_id)).unwrap();
-
-    if let Ok(sqlite::State::Row) = statement.next() {
-        let id = statement.read::<String, _>("id").unwrap();
-        let user_id = statement.read::<i64, _>("user_id").unwrap();
-
-        Some(Session { _id: id, user_id })
-    } else {
-        None
-    }
-}
-
-fn remove_session(connection: &Connection, user_id: i64) {
-    let mut statement = connection
-        .prepare("DELETE FROM sessions WHERE user_id = ?;")
-        .unwrap();
-    statement.bind((1, user_id)).unwrap();
-
-    statement.next().unwrap();
-}
-
-fn generate_session_id() -> String {
-    rand::thread_rng()
-        .sample_iter(&Alphanumeric)
-        .take(32)
-        .map(char::from)
-        .collect()
+fn signed_in_user_id(req: &Request) -> Option<i64> {
+    req.private_cookie("user_id")?.value().parse().ok()
 }
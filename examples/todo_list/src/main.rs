@@ -1,144 +1,125 @@
-use json::{object, JsonValue};
-use serwer::{route, Data, Serwer, StatusCode};
-use std::{
-    fs,
-    sync::atomic::{AtomicUsize, Ordering},
-};
+use serde::{Deserialize, Serialize};
+use serwer::{Data, ETagMode, Json, Method, PathParams, Serwer, StaticFiles, StatusCode};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[derive(Clone, Serialize)]
+struct Task {
+    id: usize,
+    description: String,
+    completed: bool,
+}
+
+#[derive(Deserialize)]
+struct NewTask {
+    description: String,
+}
+
+#[derive(Deserialize)]
+struct UpdateTask {
+    completed: bool,
+}
+
+#[derive(Deserialize)]
+struct TaskParams {
+    id: usize,
+}
+
+#[derive(Serialize)]
+struct Message {
+    status: &'static str,
+    message: &'static str,
+}
+
+type TodoList = Vec<Task>;
 
 fn main() {
     let mut serwer = Serwer::new();
 
-    let todo_list: Data<Vec<JsonValue>> = Data::new(vec![]);
-
-    serwer.get(
-        "/tasks",
-        route! {(todo_list) move |_, mut res| {
-            let todo_list = todo_list.read();
-            res.set(StatusCode::OK, json::stringify(todo_list.as_slice()));
-            res
-        }},
-    );
-
-    serwer.get(
-        "/task/<id>",
-        route! {(todo_list) move |req, mut res| {
-            let todo_list = todo_list.read();
-            let id = req.param("id").unwrap_or_default();
-
-            for task in todo_list.iter() {
-                if task["id"].to_string() == id {
-                    res.set(StatusCode::OK, json::stringify(task.clone()));
-                    return res;
-                }
-            }
-
-            res.set(StatusCode::NotFound, (object! {
-                status: "error",
-                message: "Task not found"
-            }).dump());
-            res
-
-        }},
-    );
-
-    serwer.get(
-        "/",
-        route! {() move |_, mut res| {
-            let body = fs::read_to_string("static/index.html").unwrap();
-            res.set(StatusCode::OK, body);
-            res
-        }},
-    );
-
-    serwer.post(
-        "/task",
-        route! {(todo_list) move |req, mut res| {
-            let mut todo_list = todo_list.write();
-            let mut task = json::parse(req.body().unwrap().as_str()).unwrap();
-
-            if task["description"].is_null() {
-                res.set(StatusCode::BadRequest, (object! {
-                    status: "error",
-                    message: "Task description is required"
-                }).dump());
-                return res;
-            }
-
-            task["id"] = id().into();
-            task["completed"] = false.into();
-
-            todo_list.push(task);
-
-            res.set(StatusCode::Created, (object! {
-                status: "success",
-                message: "Task added successfully"
-            }).dump());
-            res
-        }},
-    );
-
-    serwer.patch(
-        "/task/<id>",
-        route! {(todo_list) move |req, mut res| {
-            let mut todo_list = todo_list.write();
-            let id = req.param("id").unwrap_or_default();
-            let completed = json::parse(req.body().unwrap().as_str()).unwrap();
-
-            if completed["completed"].is_null() || !completed["completed"].is_boolean() {
-                res.set(StatusCode::BadRequest, (object! {
-                    status: "error",
-                    message: "Completed field is required"
-                }).dump());
-                return res;
-            }
-
-            for task in todo_list.iter_mut() {
-                if task["id"].to_string() == id {
-                    task["completed"] = completed["completed"].clone().into();
-                    res.set(StatusCode::Accepted, (object! {
-                        status: "success",
-                        message: "Task updated successfully"
-                    }).dump());
-                    return res;
-                }
-            }
-
-            res.set(StatusCode::NotFound, (object! {
-                status: "error",
-                message: "Task not found"
-            }).dump());
-            res
-        }},
-    );
-
-    serwer.delete(
-        "/task/<id>",
-        route! {(todo_list) move |req, mut res| {
-            let mut todo_list = todo_list.write();
-            let id = req.param("id").unwrap_or_default();
-
-            for (index, task) in todo_list.iter_mut().enumerate() {
-                if task["id"].to_string() == id {
-                    todo_list.remove(index);
-                    res.set(StatusCode::Accepted, (object! {
-                        status: "success",
-                        message: "Task deleted successfully"
-                    }).dump());
-                    return res;
-                }
-            }
-
-            res.set(StatusCode::NotFound, (object! {
-                status: "error",
-                message: "Task not found"
-            }).dump());
-            res
-        }},
-    );
+    serwer.manage(Data::new(TodoList::new()));
+    serwer.mount("/", StaticFiles::new("static"));
+    serwer.etags(ETagMode::Strong);
+
+    serwer.route(Method::GET, "/tasks", tasks);
+    serwer.route(Method::GET, "/task/<id>", task);
+    serwer.route(Method::POST, "/task", create_task);
+    serwer.route(Method::PATCH, "/task/<id>", update_task);
+    serwer.route(Method::DELETE, "/task/<id>", delete_task);
 
     serwer.listen(7878);
 }
 
+fn tasks(todo_list: Data<TodoList>) -> Json<TodoList> {
+    Json(todo_list.read().clone())
+}
+
+fn task(
+    todo_list: Data<TodoList>,
+    PathParams(params): PathParams<TaskParams>,
+) -> Result<Json<Task>, (StatusCode, Json<Message>)> {
+    let todo_list = todo_list.read();
+
+    match todo_list.iter().find(|task| task.id == params.id) {
+        Some(task) => Ok(Json(task.clone())),
+        None => Err(not_found()),
+    }
+}
+
+fn create_task(
+    todo_list: Data<TodoList>,
+    Json(new_task): Json<NewTask>,
+) -> (StatusCode, Json<Message>) {
+    todo_list.write().push(Task {
+        id: id(),
+        description: new_task.description,
+        completed: false,
+    });
+
+    message(StatusCode::Created, "success", "Task added successfully")
+}
+
+fn update_task(
+    todo_list: Data<TodoList>,
+    PathParams(params): PathParams<TaskParams>,
+    Json(update): Json<UpdateTask>,
+) -> (StatusCode, Json<Message>) {
+    let mut todo_list = todo_list.write();
+
+    match todo_list.iter_mut().find(|task| task.id == params.id) {
+        Some(task) => {
+            task.completed = update.completed;
+            message(StatusCode::Accepted, "success", "Task updated successfully")
+        }
+        None => not_found(),
+    }
+}
+
+fn delete_task(
+    todo_list: Data<TodoList>,
+    PathParams(params): PathParams<TaskParams>,
+) -> (StatusCode, Json<Message>) {
+    let mut todo_list = todo_list.write();
+
+    match todo_list.iter().position(|task| task.id == params.id) {
+        Some(index) => {
+            todo_list.remove(index);
+            message(StatusCode::Accepted, "success", "Task deleted successfully")
+        }
+        None => not_found(),
+    }
+}
+
+fn message(
+    status_code: StatusCode,
+    status: &'static str,
+    message: &'static str,
+) -> (StatusCode, Json<Message>) {
+    (status_code, Json(Message { status, message }))
+}
+
+fn not_found() -> (StatusCode, Json<Message>) {
+    message(StatusCode::NotFound, "error", "Task not found")
+}
+
 static COUNTER: AtomicUsize = AtomicUsize::new(1);
 
 fn id() -> usize {
@@ -0,0 +1,192 @@
+use std::{
+    fs::File,
+    io::{self, Read, Seek, SeekFrom, Write},
+    path::PathBuf,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Body {
+    Bytes(Vec<u8>),
+    File {
+        path: PathBuf,
+        offset: u64,
+        length: u64,
+    },
+    Chunks(Vec<Body>),
+}
+
+impl Body {
+    pub fn len(&self) -> u64 {
+        match self {
+            Body::Bytes(bytes) => bytes.len() as u64,
+            Body::File { length, .. } => *length,
+            Body::Chunks(chunks) => chunks.iter().map(Body::len).sum(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn slice(&self, start: u64, end: u64) -> Body {
+        match self {
+            Body::Bytes(bytes) => Body::Bytes(bytes[start as usize..=end as usize].to_vec()),
+            Body::File { path, offset, .. } => Body::File {
+                path: path.clone(),
+                offset: offset + start,
+                length: end - start + 1,
+            },
+            Body::Chunks(chunks) => {
+                let mut sliced = vec![];
+                let mut position = 0;
+
+                for chunk in chunks {
+                    let chunk_end = position + chunk.len();
+
+                    if !chunk.is_empty() && chunk_end > start && position <= end {
+                        sliced.push(chunk.slice(
+                            start.saturating_sub(position),
+                            end.min(chunk_end - 1) - position,
+                        ));
+                    }
+
+                    position = chunk_end;
+                }
+
+                Body::Chunks(sliced)
+            }
+        }
+    }
+
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        match self {
+            Body::Bytes(bytes) => writer.write_all(bytes),
+            Body::File {
+                path,
+                offset,
+                length,
+            } => {
+                let mut file = File::open(path)?;
+                file.seek(SeekFrom::Start(*offset))?;
+
+                if io::copy(&mut file.take(*length), writer)? < *length {
+                    return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+                }
+
+                Ok(())
+            }
+            Body::Chunks(chunks) => {
+                for chunk in chunks {
+                    chunk.write_to(writer)?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+
+    pub fn to_bytes(&self) -> io::Result<Vec<u8>> {
+        match self {
+            Body::Bytes(bytes) => Ok(bytes.clone()),
+            _ => {
+                let mut bytes = vec![];
+                self.write_to(&mut bytes)?;
+
+                Ok(bytes)
+            }
+        }
+    }
+}
+
+impl Default for Body {
+    fn default() -> Self {
+        Body::Bytes(vec![])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, fs};
+
+    fn file(name: &str, content: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("serwer_body_{}", name));
+        fs::write(&path, content).unwrap();
+
+        path
+    }
+
+    #[test]
+    fn test_len() {
+        assert_eq!(Body::default().len(), 0);
+        assert!(Body::default().is_empty());
+        assert_eq!(Body::Bytes(b"Hello".to_vec()).len(), 5);
+        assert_eq!(
+            Body::Chunks(vec![
+                Body::Bytes(b"Hello".to_vec()),
+                Body::File {
+                    path: PathBuf::from("file.txt"),
+                    offset: 10,
+                    length: 6
+                }
+            ])
+            .len(),
+            11
+        );
+    }
+
+    #[test]
+    fn test_to_bytes_file() {
+        let path = file("to_bytes", "Hello World");
+
+        let body = Body::File {
+            path: path.clone(),
+            offset: 6,
+            length: 5,
+        };
+        assert_eq!(body.to_bytes().unwrap(), b"World");
+
+        let body = Body::File {
+            path,
+            offset: 6,
+            length: 10,
+        };
+        assert_eq!(
+            body.to_bytes().unwrap_err().kind(),
+            io::ErrorKind::UnexpectedEof
+        );
+    }
+
+    #[test]
+    fn test_slice() {
+        let path = file("slice", "Hello World");
+
+        assert_eq!(
+            Body::Bytes(b"Hello World".to_vec()).slice(0, 4),
+            Body::Bytes(b"Hello".to_vec())
+        );
+        assert_eq!(
+            Body::File {
+                path: path.clone(),
+                offset: 0,
+                length: 11
+            }
+            .slice(6, 10)
+            .to_bytes()
+            .unwrap(),
+            b"World"
+        );
+
+        let body = Body::Chunks(vec![
+            Body::Bytes(b"Hello ".to_vec()),
+            Body::File {
+                path,
+                offset: 6,
+                length: 5,
+            },
+            Body::Bytes(b"!".to_vec()),
+        ]);
+        assert_eq!(body.slice(4, 8).to_bytes().unwrap(), b"o Wor");
+        assert_eq!(body.slice(10, 11).to_bytes().unwrap(), b"d!");
+    }
+}
@@ -0,0 +1,112 @@
+use crate::SerwerError;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ByteRange {
+    FromTo(u64, u64),
+    From(u64),
+    Last(u64),
+}
+
+impl ByteRange {
+    pub fn from_string(string: &str) -> Result<Self, SerwerError> {
+        let (start, end) = string
+            .trim()
+            .split_once('-')
+            .ok_or(SerwerError::InvalidHeaderValue(String::from(string)))?;
+
+        let parse = |value: &str| -> Result<u64, SerwerError> {
+            if value.is_empty() || !value.chars().all(|c| c.is_ascii_digit()) {
+                return Err(SerwerError::InvalidHeaderValue(String::from(string)));
+            }
+
+            Ok(value.parse()?)
+        };
+
+        match (start.is_empty(), end.is_empty()) {
+            (true, false) => Ok(ByteRange::Last(parse(end)?)),
+            (false, true) => Ok(ByteRange::From(parse(start)?)),
+            (false, false) => {
+                let (start, end) = (parse(start)?, parse(end)?);
+
+                if start > end {
+                    return Err(SerwerError::InvalidHeaderValue(String::from(string)));
+                }
+
+                Ok(ByteRange::FromTo(start, end))
+            }
+            (true, true) => Err(SerwerError::InvalidHeaderValue(String::from(string))),
+        }
+    }
+
+    pub fn bounds(&self, length: u64) -> Option<(u64, u64)> {
+        match *self {
+            ByteRange::FromTo(start, end) if start < length => Some((start, end.min(length - 1))),
+            ByteRange::From(start) if start < length => Some((start, length - 1)),
+            ByteRange::Last(last) if last > 0 && length > 0 => {
+                Some((length.saturating_sub(last), length - 1))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ByteRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ByteRange::FromTo(start, end) => write!(f, "{}-{}", start, end),
+            ByteRange::From(start) => write!(f, "{}-", start),
+            ByteRange::Last(last) => write!(f, "-{}", last),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_string() {
+        assert_eq!(
+            ByteRange::from_string("0-499").unwrap(),
+            ByteRange::FromTo(0, 499)
+        );
+        assert_eq!(
+            ByteRange::from_string("9500-").unwrap(),
+            ByteRange::From(9500)
+        );
+        assert_eq!(
+            ByteRange::from_string("-500").unwrap(),
+            ByteRange::Last(500)
+        );
+    }
+
+    #[test]
+    fn test_from_string_invalid() {
+        for string in ["", "-", "500", "10-5", "a-5", "+1-5"] {
+            assert!(matches!(
+                ByteRange::from_string(string),
+                Err(SerwerError::InvalidHeaderValue(error_string)) if error_string == string
+            ));
+        }
+    }
+
+    #[test]
+    fn test_bounds() {
+        assert_eq!(ByteRange::FromTo(0, 499).bounds(1000), Some((0, 499)));
+        assert_eq!(ByteRange::FromTo(900, 1999).bounds(1000), Some((900, 999)));
+        assert_eq!(ByteRange::FromTo(1000, 1999).bounds(1000), None);
+        assert_eq!(ByteRange::From(100).bounds(1000), Some((100, 999)));
+        assert_eq!(ByteRange::Last(100).bounds(1000), Some((900, 999)));
+        assert_eq!(ByteRange::Last(2000).bounds(1000), Some((0, 999)));
+        assert_eq!(ByteRange::Last(0).bounds(1000), None);
+        assert_eq!(ByteRange::Last(10).bounds(0), None);
+    }
+
+    #[test]
+    fn test_to_string() {
+        assert_eq!(ByteRange::FromTo(0, 499).to_string(), "0-499");
+        assert_eq!(ByteRange::From(9500).to_string(), "9500-");
+        assert_eq!(ByteRange::Last(500).to_string(), "-500");
+    }
+}
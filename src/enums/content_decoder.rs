@@ -0,0 +1,78 @@
+use crate::{ContentEncoding, SerwerError};
+use std::io::{self, Read};
+
+pub enum ContentDecoder<R: Read> {
+    Identity(R),
+    #[cfg(feature = "gzip")]
+    Gzip(flate2::read::GzDecoder<R>),
+    #[cfg(feature = "deflate")]
+    Deflate(flate2::read::ZlibDecoder<R>),
+    #[cfg(feature = "brotli")]
+    Brotli(Box<brotli::Decompressor<R>>),
+}
+
+impl<R: Read> ContentDecoder<R> {
+    pub fn new(encoding: ContentEncoding, reader: R) -> Result<Self, SerwerError> {
+        match encoding {
+            ContentEncoding::Identity => Ok(ContentDecoder::Identity(reader)),
+            #[cfg(feature = "gzip")]
+            ContentEncoding::Gzip => Ok(ContentDecoder::Gzip(flate2::read::GzDecoder::new(reader))),
+            #[cfg(feature = "deflate")]
+            ContentEncoding::Deflate => Ok(ContentDecoder::Deflate(
+                flate2::read::ZlibDecoder::new(reader),
+            )),
+            #[cfg(feature = "brotli")]
+            ContentEncoding::Brotli => Ok(ContentDecoder::Brotli(Box::new(
+                brotli::Decompressor::new(reader, 4096),
+            ))),
+            #[allow(unreachable_patterns)]
+            _ => Err(SerwerError::UnsupportedEncoding(encoding.to_string())),
+        }
+    }
+}
+
+impl<R: Read> Read for ContentDecoder<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            ContentDecoder::Identity(reader) => reader.read(buf),
+            #[cfg(feature = "gzip")]
+            ContentDecoder::Gzip(decoder) => decoder.read(buf),
+            #[cfg(feature = "deflate")]
+            ContentDecoder::Deflate(decoder) => decoder.read(buf),
+            #[cfg(feature = "brotli")]
+            ContentDecoder::Brotli(decoder) => decoder.read(buf),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identity() {
+        let mut decoder =
+            ContentDecoder::new(ContentEncoding::Identity, &b"Hello World"[..]).unwrap();
+        let mut result = String::new();
+        decoder.read_to_string(&mut result).unwrap();
+
+        assert_eq!(result, "Hello World");
+    }
+
+    #[cfg(feature = "deflate")]
+    #[test]
+    fn test_streaming() {
+        let encoded = ContentEncoding::Deflate
+            .encode("Hello World ".repeat(100).as_bytes())
+            .unwrap();
+        let mut decoder = ContentDecoder::new(ContentEncoding::Deflate, &encoded[..]).unwrap();
+        let mut buffer = [0; 12];
+
+        for _ in 0..100 {
+            decoder.read_exact(&mut buffer).unwrap();
+            assert_eq!(&buffer, b"Hello World ");
+        }
+
+        assert_eq!(decoder.read(&mut buffer).unwrap(), 0);
+    }
+}
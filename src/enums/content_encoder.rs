@@ -0,0 +1,107 @@
+use crate::{ContentEncoding, SerwerError};
+#[cfg(any(feature = "gzip", feature = "deflate"))]
+use flate2::Compression;
+use std::io::{self, Write};
+
+pub enum ContentEncoder<W: Write> {
+    Identity(W),
+    #[cfg(feature = "gzip")]
+    Gzip(flate2::write::GzEncoder<W>),
+    #[cfg(feature = "deflate")]
+    Deflate(flate2::write::ZlibEncoder<W>),
+    #[cfg(feature = "brotli")]
+    Brotli(Box<brotli::CompressorWriter<W>>),
+}
+
+impl<W: Write> ContentEncoder<W> {
+    pub fn new(encoding: ContentEncoding, writer: W) -> Result<Self, SerwerError> {
+        match encoding {
+            ContentEncoding::Identity => Ok(ContentEncoder::Identity(writer)),
+            #[cfg(feature = "gzip")]
+            ContentEncoding::Gzip => Ok(ContentEncoder::Gzip(flate2::write::GzEncoder::new(
+                writer,
+                Compression::default(),
+            ))),
+            #[cfg(feature = "deflate")]
+            ContentEncoding::Deflate => Ok(ContentEncoder::Deflate(
+                flate2::write::ZlibEncoder::new(writer, Compression::default()),
+            )),
+            #[cfg(feature = "brotli")]
+            ContentEncoding::Brotli => Ok(ContentEncoder::Brotli(Box::new(
+                brotli::CompressorWriter::new(writer, 4096, 5, 22),
+            ))),
+            #[allow(unreachable_patterns)]
+            _ => Err(SerwerError::UnsupportedEncoding(encoding.to_string())),
+        }
+    }
+
+    pub fn finish(self) -> io::Result<W> {
+        match self {
+            ContentEncoder::Identity(writer) => Ok(writer),
+            #[cfg(feature = "gzip")]
+            ContentEncoder::Gzip(encoder) => encoder.finish(),
+            #[cfg(feature = "deflate")]
+            ContentEncoder::Deflate(encoder) => encoder.finish(),
+            #[cfg(feature = "brotli")]
+            ContentEncoder::Brotli(encoder) => Ok(encoder.into_inner()),
+        }
+    }
+}
+
+impl<W: Write> Write for ContentEncoder<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            ContentEncoder::Identity(writer) => writer.write(buf),
+            #[cfg(feature = "gzip")]
+            ContentEncoder::Gzip(encoder) => encoder.write(buf),
+            #[cfg(feature = "deflate")]
+            ContentEncoder::Deflate(encoder) => encoder.write(buf),
+            #[cfg(feature = "brotli")]
+            ContentEncoder::Brotli(encoder) => encoder.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            ContentEncoder::Identity(writer) => writer.flush(),
+            #[cfg(feature = "gzip")]
+            ContentEncoder::Gzip(encoder) => encoder.flush(),
+            #[cfg(feature = "deflate")]
+            ContentEncoder::Deflate(encoder) => encoder.flush(),
+            #[cfg(feature = "brotli")]
+            ContentEncoder::Brotli(encoder) => encoder.flush(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identity() {
+        let mut encoder = ContentEncoder::new(ContentEncoding::Identity, vec![]).unwrap();
+        encoder.write_all(b"Hello ").unwrap();
+        encoder.write_all(b"World").unwrap();
+        encoder.flush().unwrap();
+
+        assert_eq!(encoder.finish().unwrap(), b"Hello World");
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn test_streaming() {
+        let mut encoder = ContentEncoder::new(ContentEncoding::Gzip, vec![]).unwrap();
+
+        for _ in 0..100 {
+            encoder.write_all(b"Hello World ").unwrap();
+        }
+
+        let result = encoder.finish().unwrap();
+        let mut decoded = String::new();
+        io::Read::read_to_string(&mut flate2::read::GzDecoder::new(&result[..]), &mut decoded)
+            .unwrap();
+
+        assert_eq!(decoded, "Hello World ".repeat(100));
+    }
+}
@@ -0,0 +1,238 @@
+use crate::{ContentDecoder, ContentEncoder, SerwerError};
+use std::{
+    fmt,
+    io::{Read, Write},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ContentEncoding {
+    Identity,
+    Gzip,
+    Deflate,
+    Brotli,
+}
+
+impl ContentEncoding {
+    pub fn from_string(string: &str) -> Result<Self, SerwerError> {
+        match string.trim().to_lowercase().as_str() {
+            "identity" => Ok(ContentEncoding::Identity),
+            "gzip" | "x-gzip" => Ok(ContentEncoding::Gzip),
+            "deflate" => Ok(ContentEncoding::Deflate),
+            "br" => Ok(ContentEncoding::Brotli),
+            _ => Err(SerwerError::UnsupportedEncoding(String::from(string))),
+        }
+    }
+
+    pub fn supported() -> Vec<Self> {
+        [
+            ContentEncoding::Brotli,
+            ContentEncoding::Gzip,
+            ContentEncoding::Deflate,
+        ]
+        .into_iter()
+        .filter(ContentEncoding::is_supported)
+        .collect()
+    }
+
+    pub fn is_supported(&self) -> bool {
+        match self {
+            ContentEncoding::Identity => true,
+            ContentEncoding::Gzip => cfg!(feature = "gzip"),
+            ContentEncoding::Deflate => cfg!(feature = "deflate"),
+            ContentEncoding::Brotli => cfg!(feature = "brotli"),
+        }
+    }
+
+    pub fn encoder<W: Write>(&self, writer: W) -> Result<ContentEncoder<W>, SerwerError> {
+        ContentEncoder::new(*self, writer)
+    }
+
+    pub fn encode(&self, bytes: &[u8]) -> Result<Vec<u8>, SerwerError> {
+        let mut encoder = self.encoder(vec![])?;
+        encoder.write_all(bytes)?;
+
+        Ok(encoder.finish()?)
+    }
+
+    pub fn decoder<R: Read>(&self, reader: R) -> Result<ContentDecoder<R>, SerwerError> {
+        ContentDecoder::new(*self, reader)
+    }
+
+    pub fn decode(&self, bytes: &[u8], max_size: usize) -> Result<Vec<u8>, SerwerError> {
+        let mut decoded = vec![];
+
+        self.decoder(bytes)?
+            .take(max_size as u64 + 1)
+            .read_to_end(&mut decoded)
+            .map_err(|error| SerwerError::DecodeError(error.to_string()))?;
+
+        if decoded.len() > max_size {
+            return Err(SerwerError::BodyTooLarge(max_size.to_string()));
+        }
+
+        Ok(decoded)
+    }
+}
+
+impl fmt::Display for ContentEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ContentEncoding::Identity => write!(f, "identity"),
+            ContentEncoding::Gzip => write!(f, "gzip"),
+            ContentEncoding::Deflate => write!(f, "deflate"),
+            ContentEncoding::Brotli => write!(f, "br"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_string() {
+        assert_eq!(
+            ContentEncoding::from_string("GZIP").unwrap(),
+            ContentEncoding::Gzip
+        );
+        assert_eq!(
+            ContentEncoding::from_string("x-gzip").unwrap(),
+            ContentEncoding::Gzip
+        );
+        assert_eq!(
+            ContentEncoding::from_string("br").unwrap(),
+            ContentEncoding::Brotli
+        );
+        assert!(matches!(
+            ContentEncoding::from_string("compress"),
+            Err(SerwerError::UnsupportedEncoding(error_string)) if &error_string == "compress"
+        ));
+    }
+
+    #[test]
+    fn test_to_string() {
+        for encoding in [
+            ContentEncoding::Identity,
+            ContentEncoding::Gzip,
+            ContentEncoding::Deflate,
+            ContentEncoding::Brotli,
+        ] {
+            assert_eq!(
+                ContentEncoding::from_string(&encoding.to_string()).unwrap(),
+                encoding
+            );
+        }
+    }
+
+    #[test]
+    fn test_supported() {
+        let supported = ContentEncoding::supported();
+
+        assert_eq!(
+            supported.contains(&ContentEncoding::Gzip),
+            cfg!(feature = "gzip")
+        );
+        assert_eq!(
+            supported.contains(&ContentEncoding::Brotli),
+            cfg!(feature = "brotli")
+        );
+        assert!(!supported.contains(&ContentEncoding::Identity));
+        assert!(ContentEncoding::Identity.is_supported());
+    }
+
+    #[test]
+    fn test_encode_identity() {
+        assert_eq!(
+            ContentEncoding::Identity.encode(b"Hello World").unwrap(),
+            b"Hello World"
+        );
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn test_encode_gzip() {
+        let result = ContentEncoding::Gzip.encode(&[b'a'; 1000]).unwrap();
+
+        assert_eq!(&result[..2], &[0x1f, 0x8b]);
+        assert!(result.len() < 100);
+    }
+
+    #[cfg(feature = "deflate")]
+    #[test]
+    fn test_encode_deflate() {
+        let result = ContentEncoding::Deflate.encode(&[b'a'; 1000]).unwrap();
+
+        assert_eq!(result[0], 0x78);
+        assert!(result.len() < 100);
+    }
+
+    #[cfg(feature = "brotli")]
+    #[test]
+    fn test_encode_brotli() {
+        let result = ContentEncoding::Brotli.encode(&[b'a'; 1000]).unwrap();
+
+        assert!(result.len() < 100);
+    }
+
+    #[cfg(not(feature = "brotli"))]
+    #[test]
+    fn test_encode_unsupported() {
+        assert!(matches!(
+            ContentEncoding::Brotli.encode(b"Hello World"),
+            Err(SerwerError::UnsupportedEncoding(error_string)) if &error_string == "br"
+        ));
+    }
+
+    #[test]
+    fn test_decode_identity() {
+        assert_eq!(
+            ContentEncoding::Identity
+                .decode(b"Hello World", 11)
+                .unwrap(),
+            b"Hello World"
+        );
+        assert!(matches!(
+            ContentEncoding::Identity.decode(b"Hello World", 10),
+            Err(SerwerError::BodyTooLarge(error_string)) if &error_string == "10"
+        ));
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn test_decode_gzip() {
+        let encoded = ContentEncoding::Gzip.encode(&[b'a'; 1000]).unwrap();
+
+        assert_eq!(
+            ContentEncoding::Gzip.decode(&encoded, 1000).unwrap(),
+            vec![b'a'; 1000]
+        );
+        assert!(matches!(
+            ContentEncoding::Gzip.decode(&encoded, 999),
+            Err(SerwerError::BodyTooLarge(_))
+        ));
+        assert!(matches!(
+            ContentEncoding::Gzip.decode(b"Hello World", 1000),
+            Err(SerwerError::DecodeError(_))
+        ));
+    }
+
+    #[cfg(feature = "brotli")]
+    #[test]
+    fn test_decode_brotli() {
+        let encoded = ContentEncoding::Brotli.encode(&[b'a'; 1000]).unwrap();
+
+        assert_eq!(
+            ContentEncoding::Brotli.decode(&encoded, 1000).unwrap(),
+            vec![b'a'; 1000]
+        );
+    }
+
+    #[cfg(not(feature = "gzip"))]
+    #[test]
+    fn test_decode_unsupported() {
+        assert!(matches!(
+            ContentEncoding::Gzip.decode(b"Hello World", 1000),
+            Err(SerwerError::UnsupportedEncoding(error_string)) if &error_string == "gzip"
+        ));
+    }
+}
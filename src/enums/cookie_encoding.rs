@@ -0,0 +1,70 @@
+use crate::{base64_decode, base64_encode, decode_path, encode, unquote, SerwerError};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CookieEncoding {
+    Raw,
+    Percent,
+    Base64Url,
+}
+
+impl CookieEncoding {
+    pub fn encode(&self, value: &str) -> String {
+        match self {
+            CookieEncoding::Raw => String::from(value),
+            CookieEncoding::Percent => encode(value),
+            CookieEncoding::Base64Url => base64_encode(value.as_bytes(), true),
+        }
+    }
+
+    pub fn decode(&self, value: &str) -> Result<String, SerwerError> {
+        match self {
+            CookieEncoding::Raw => Ok(String::from(value)),
+            CookieEncoding::Percent => decode_path(&unquote(value)),
+            CookieEncoding::Base64Url => String::from_utf8(base64_decode(&unquote(value), true)?)
+                .map_err(|_| SerwerError::DecodeError(String::from(value))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode() {
+        let value = r#"{"theme": "dark", "city": "Łódź"}"#;
+
+        assert_eq!(CookieEncoding::Raw.encode(value), value);
+        assert_eq!(
+            CookieEncoding::Percent.encode(value),
+            "%7B%22theme%22%3A%20%22dark%22%2C%20%22city%22%3A%20%22%C5%81%C3%B3d%C5%BA%22%7D"
+        );
+        assert_eq!(
+            CookieEncoding::Base64Url.encode(value),
+            "eyJ0aGVtZSI6ICJkYXJrIiwgImNpdHkiOiAixYHDs2TFuiJ9"
+        );
+    }
+
+    #[test]
+    fn test_decode() {
+        let value = r#"{"theme": "dark", "city": "Łódź"}"#;
+
+        for encoding in [
+            CookieEncoding::Raw,
+            CookieEncoding::Percent,
+            CookieEncoding::Base64Url,
+        ] {
+            assert_eq!(encoding.decode(&encoding.encode(value)).unwrap(), value);
+        }
+
+        assert_eq!(CookieEncoding::Percent.decode("\"a%2Cb\"").unwrap(), "a,b");
+        assert!(matches!(
+            CookieEncoding::Percent.decode("%FF"),
+            Err(SerwerError::DecodeError(_))
+        ));
+        assert!(matches!(
+            CookieEncoding::Base64Url.decode("_w"),
+            Err(SerwerError::DecodeError(error_string)) if &error_string == "_w"
+        ));
+    }
+}
@@ -0,0 +1,30 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CookiePriority {
+    Low,
+    Medium,
+    High,
+}
+
+impl fmt::Display for CookiePriority {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CookiePriority::Low => write!(f, "Low"),
+            CookiePriority::Medium => write!(f, "Medium"),
+            CookiePriority::High => write!(f, "High"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_string() {
+        assert_eq!(CookiePriority::Low.to_string(), "Low");
+        assert_eq!(CookiePriority::Medium.to_string(), "Medium");
+        assert_eq!(CookiePriority::High.to_string(), "High");
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{base64_decode, base64_encode, SerwerError};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Credentials {
+    Basic { username: String, password: String },
+    Bearer(String),
+    Other { scheme: String, token: String },
+}
+
+impl Credentials {
+    pub fn from_string(string: &str) -> Result<Self, SerwerError> {
+        let (scheme, token) = string.trim().split_once(' ').unwrap_or((string, ""));
+        let token = token.trim();
+
+        if scheme.is_empty() || token.is_empty() {
+            return Err(SerwerError::InvalidHeaderValue(String::from(string)));
+        }
+
+        match scheme.to_lowercase().as_str() {
+            "basic" => {
+                let decoded = String::from_utf8(base64_decode(token, false)?)?;
+                let (username, password) = decoded
+                    .split_once(':')
+                    .ok_or(SerwerError::InvalidHeaderValue(String::from(string)))?;
+
+                Ok(Credentials::Basic {
+                    username: String::from(username),
+                    password: String::from(password),
+                })
+            }
+            "bearer" => Ok(Credentials::Bearer(String::from(token))),
+            _ => Ok(Credentials::Other {
+                scheme: String::from(scheme),
+                token: String::from(token),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Credentials::Basic { username, password } => write!(
+                f,
+                "Basic {}",
+                base64_encode(format!("{}:{}", username, password).as_bytes(), false)
+            ),
+            Credentials::Bearer(token) => write!(f, "Bearer {}", token),
+            Credentials::Other { scheme, token } => write!(f, "{} {}", scheme, token),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_string() {
+        assert_eq!(
+            Credentials::from_string("Basic dXNlcjpwYTpzcw==").unwrap(),
+            Credentials::Basic {
+                username: String::from("user"),
+                password: String::from("pa:ss")
+            }
+        );
+        assert_eq!(
+            Credentials::from_string("bearer abc.def").unwrap(),
+            Credentials::Bearer(String::from("abc.def"))
+        );
+        assert_eq!(
+            Credentials::from_string("Digest username=\"user\"").unwrap(),
+            Credentials::Other {
+                scheme: String::from("Digest"),
+                token: String::from("username=\"user\"")
+            }
+        );
+    }
+
+    #[test]
+    fn test_from_string_invalid() {
+        assert!(matches!(
+            Credentials::from_string("Bearer"),
+            Err(SerwerError::InvalidHeaderValue(error_string)) if &error_string == "Bearer"
+        ));
+        assert!(matches!(
+            Credentials::from_string("Basic dXNlcg=="),
+            Err(SerwerError::InvalidHeaderValue(error_string)) if &error_string == "Basic dXNlcg=="
+        ));
+        assert!(matches!(
+            Credentials::from_string("Basic !!!"),
+            Err(SerwerError::DecodeError(_))
+        ));
+    }
+
+    #[test]
+    fn test_to_string() {
+        let credentials = Credentials::Basic {
+            username: String::from("user"),
+            password: String::from("pass"),
+        };
+        assert_eq!(credentials.to_string(), "Basic dXNlcjpwYXNz");
+        assert_eq!(
+            Credentials::from_string(&credentials.to_string()).unwrap(),
+            credentials
+        );
+        assert_eq!(
+            Credentials::Bearer(String::from("token")).to_string(),
+            "Bearer token"
+        );
+    }
+}
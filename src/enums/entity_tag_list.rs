@@ -0,0 +1,106 @@
+use crate::{split_quoted, EntityTag, SerwerError};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EntityTagList {
+    Any,
+    Tags(Vec<EntityTag>),
+}
+
+impl EntityTagList {
+    pub fn from_string(string: &str) -> Result<Self, SerwerError> {
+        if string.trim() == "*" {
+            return Ok(EntityTagList::Any);
+        }
+
+        let mut tags = vec![];
+
+        for part in split_quoted(string, ',') {
+            if !part.is_empty() {
+                tags.push(EntityTag::from_string(&part)?);
+            }
+        }
+
+        if tags.is_empty() {
+            return Err(SerwerError::InvalidHeaderValue(String::from(string)));
+        }
+
+        Ok(EntityTagList::Tags(tags))
+    }
+
+    pub fn matches_strong(&self, tag: &EntityTag) -> bool {
+        match self {
+            EntityTagList::Any => true,
+            EntityTagList::Tags(tags) => tags.iter().any(|other| other.strong_eq(tag)),
+        }
+    }
+
+    pub fn matches_weak(&self, tag: &EntityTag) -> bool {
+        match self {
+            EntityTagList::Any => true,
+            EntityTagList::Tags(tags) => tags.iter().any(|other| other.weak_eq(tag)),
+        }
+    }
+}
+
+impl fmt::Display for EntityTagList {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EntityTagList::Any => write!(f, "*"),
+            EntityTagList::Tags(tags) => write!(
+                f,
+                "{}",
+                tags.iter()
+                    .map(|tag| tag.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_string() {
+        assert_eq!(EntityTagList::from_string("*").unwrap(), EntityTagList::Any);
+        assert_eq!(
+            EntityTagList::from_string("\"a\", W/\"b\"").unwrap(),
+            EntityTagList::Tags(vec![EntityTag::strong("a"), EntityTag::weak("b")])
+        );
+    }
+
+    #[test]
+    fn test_from_string_invalid() {
+        assert!(matches!(
+            EntityTagList::from_string(""),
+            Err(SerwerError::InvalidHeaderValue(error_string)) if error_string.is_empty()
+        ));
+        assert!(matches!(
+            EntityTagList::from_string("\"a\", b"),
+            Err(SerwerError::InvalidHeaderValue(error_string)) if &error_string == "b"
+        ));
+    }
+
+    #[test]
+    fn test_matches() {
+        let list = EntityTagList::from_string("\"a\", W/\"b\"").unwrap();
+
+        assert!(list.matches_strong(&EntityTag::strong("a")));
+        assert!(!list.matches_strong(&EntityTag::strong("b")));
+        assert!(list.matches_weak(&EntityTag::strong("b")));
+        assert!(!list.matches_weak(&EntityTag::strong("c")));
+        assert!(EntityTagList::Any.matches_strong(&EntityTag::weak("c")));
+    }
+
+    #[test]
+    fn test_to_string() {
+        assert_eq!(EntityTagList::Any.to_string(), "*");
+        assert_eq!(
+            EntityTagList::Tags(vec![EntityTag::strong("a"), EntityTag::weak("b")]).to_string(),
+            "\"a\", W/\"b\""
+        );
+    }
+}
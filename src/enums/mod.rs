@@ -1,9 +1,37 @@
+mod body;
+mod byte_range;
+mod content_decoder;
+mod content_encoder;
+mod content_encoding;
+mod cookie_encoding;
+mod cookie_priority;
+mod credentials;
+mod entity_tag_list;
+mod etag_mode;
 mod method;
+mod range_condition;
+mod redirect_rule;
+mod same_site;
 mod serwer_error;
 mod status_code;
+mod trailing_slash;
 mod version;
 
+pub use body::*;
+pub use byte_range::*;
+pub use content_decoder::*;
+pub use content_encoder::*;
+pub use content_encoding::*;
+pub use cookie_encoding::*;
+pub use cookie_priority::*;
+pub use credentials::*;
+pub use entity_tag_list::*;
+pub use etag_mode::*;
 pub use method::*;
+pub use range_condition::*;
+pub use redirect_rule::*;
+pub use same_site::*;
 pub use serwer_error::*;
 pub use status_code::*;
+pub use trailing_slash::*;
 pub use version::*;
@@ -0,0 +1,60 @@
+use crate::{EntityTag, HttpDate, SerwerError};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RangeCondition {
+    EntityTag(EntityTag),
+    Date(HttpDate),
+}
+
+impl RangeCondition {
+    pub fn from_string(string: &str) -> Result<Self, SerwerError> {
+        if string.starts_with('"') || string.starts_with("W/") {
+            Ok(RangeCondition::EntityTag(EntityTag::from_string(string)?))
+        } else {
+            Ok(RangeCondition::Date(HttpDate::from_string(string)?))
+        }
+    }
+}
+
+impl fmt::Display for RangeCondition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RangeCondition::EntityTag(entity_tag) => write!(f, "{}", entity_tag),
+            RangeCondition::Date(date) => write!(f, "{}", date),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_string() {
+        assert_eq!(
+            RangeCondition::from_string("\"abc\"").unwrap(),
+            RangeCondition::EntityTag(EntityTag::strong("abc"))
+        );
+        assert_eq!(
+            RangeCondition::from_string("Sun, 06 Nov 1994 08:49:37 GMT").unwrap(),
+            RangeCondition::Date(HttpDate::from_unix_timestamp(784111777))
+        );
+        assert!(matches!(
+            RangeCondition::from_string("yesterday"),
+            Err(SerwerError::InvalidDate(error_string)) if &error_string == "yesterday"
+        ));
+    }
+
+    #[test]
+    fn test_to_string() {
+        assert_eq!(
+            RangeCondition::EntityTag(EntityTag::weak("abc")).to_string(),
+            "W/\"abc\""
+        );
+        assert_eq!(
+            RangeCondition::Date(HttpDate::from_unix_timestamp(784111777)).to_string(),
+            "Sun, 06 Nov 1994 08:49:37 GMT"
+        );
+    }
+}
@@ -0,0 +1,300 @@
+use crate::{Method, Path, Request, Route, StatusCode, TrailingSlash};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RedirectRule {
+    Path {
+        from: Path,
+        to: String,
+        status_code: StatusCode,
+    },
+    TrailingSlash(TrailingSlash),
+    Https {
+        host: Option<String>,
+        port: Option<u16>,
+    },
+}
+
+impl RedirectRule {
+    pub(crate) fn location(
+        &self,
+        request: &Request,
+        routes: &[Route],
+    ) -> Option<(StatusCode, String)> {
+        let url = request.original_url();
+        let (path, query) = match url.split_once('?') {
+            Some((path, query)) => (path, Some(query)),
+            None => (url.as_str(), None),
+        };
+
+        match self {
+            RedirectRule::Path {
+                from,
+                to,
+                status_code,
+            } => {
+                let (matches, params) = from.matches(request.path());
+
+                if !matches {
+                    return None;
+                }
+
+                let mut location = params.unwrap().fill(to)?;
+
+                if let (Some(query), false) = (query, location.contains('?')) {
+                    location = format!("{}?{}", location, query);
+                }
+
+                Some((status_code.clone(), location))
+            }
+            RedirectRule::TrailingSlash(trailing_slash) => {
+                let target = match trailing_slash {
+                    TrailingSlash::Add if !path.ends_with('/') => format!("{}/", path),
+                    TrailingSlash::Remove if path.len() > 1 && path.ends_with('/') => {
+                        path.trim_end_matches('/').to_string()
+                    }
+                    _ => return None,
+                };
+
+                let target = if target.is_empty() {
+                    String::from("/")
+                } else {
+                    target
+                };
+
+                let target_path = Path::from_string(&target).ok()?;
+                let method = request.method();
+                let matches = |path: &Path| {
+                    routes.iter().any(|route| {
+                        (route.method() == &method || route.method() == &Method::ALL)
+                            && route.path().matches(path).0
+                    })
+                };
+
+                if matches(request.path()) || !matches(&target_path) {
+                    return None;
+                }
+
+                let location = match query {
+                    Some(query) => format!("{}?{}", target, query),
+                    None => target,
+                };
+
+                Some((StatusCode::PermanentRedirect, location))
+            }
+            RedirectRule::Https { host, port } => {
+                let forwarded_proto = request.header("X-Forwarded-Proto").unwrap_or_default();
+
+                if forwarded_proto
+                    .split(',')
+                    .next()
+                    .is_some_and(|proto| proto.trim().eq_ignore_ascii_case("https"))
+                {
+                    return None;
+                }
+
+                let host = match host {
+                    Some(host) => host.clone(),
+                    None => String::from(Self::host_name(&request.header("Host")?)?),
+                };
+
+                let port = match port {
+                    Some(port) if *port != 443 => format!(":{}", port),
+                    _ => String::new(),
+                };
+
+                Some((
+                    StatusCode::PermanentRedirect,
+                    format!("https://{}{}{}", host, port, url),
+                ))
+            }
+        }
+    }
+
+    pub(crate) fn host_name(host: &str) -> Option<&str> {
+        let (name, port) = match host.strip_prefix('[') {
+            Some(rest) => {
+                let end = rest.find(']')? + 2;
+
+                if end == 2
+                    || !host[1..end - 1]
+                        .chars()
+                        .all(|c| c.is_ascii_hexdigit() || c == ':' || c == '.')
+                {
+                    return None;
+                }
+
+                host.split_at(end)
+            }
+            None => {
+                let (name, port) = host.split_at(host.find(':').unwrap_or(host.len()));
+
+                if name.is_empty()
+                    || !name
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || "-._~".contains(c))
+                {
+                    return None;
+                }
+
+                (name, port)
+            }
+        };
+
+        if !port.is_empty() {
+            let digits = port.strip_prefix(':')?;
+
+            if digits.is_empty() || digits.len() > 5 || !digits.chars().all(|c| c.is_ascii_digit())
+            {
+                return None;
+            }
+        }
+
+        Some(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request_from_bytes;
+
+    fn location(rule: &RedirectRule, request: &str, routes: &[Route]) -> Option<String> {
+        let request = request_from_bytes(request.as_bytes()).unwrap();
+
+        rule.location(&request, routes)
+            .map(|(status_code, location)| format!("{} {}", status_code.to_string(), location))
+    }
+
+    #[test]
+    fn test_location_path() {
+        let rule = RedirectRule::Path {
+            from: Path::from_string("/old/<id>").unwrap(),
+            to: String::from("/new/<id>/details"),
+            status_code: StatusCode::MovedPermanently,
+        };
+
+        assert_eq!(
+            location(&rule, "GET /old/a%2Cb?tab=1 HTTP/1.1\r\n\r\n", &[]),
+            Some(String::from(
+                "301 Moved Permanently /new/a%2Cb/details?tab=1"
+            ))
+        );
+        assert_eq!(location(&rule, "GET /old HTTP/1.1\r\n\r\n", &[]), None);
+    }
+
+    #[test]
+    fn test_location_trailing_slash() {
+        let routes = [
+            Route::new(Method::GET, "/users/", |_, res| res).unwrap(),
+            Route::new(Method::GET, "/tasks", |_, res| res).unwrap(),
+        ];
+
+        let add = RedirectRule::TrailingSlash(TrailingSlash::Add);
+        assert_eq!(
+            location(&add, "GET /users?page=2 HTTP/1.1\r\n\r\n", &routes),
+            Some(String::from("308 Permanent Redirect /users/?page=2"))
+        );
+        assert_eq!(
+            location(&add, "GET /users/ HTTP/1.1\r\n\r\n", &routes),
+            None
+        );
+        assert_eq!(
+            location(&add, "GET /missing HTTP/1.1\r\n\r\n", &routes),
+            None
+        );
+        assert_eq!(
+            location(&add, "POST /users HTTP/1.1\r\n\r\n", &routes),
+            None
+        );
+
+        let remove = RedirectRule::TrailingSlash(TrailingSlash::Remove);
+        assert_eq!(
+            location(&remove, "GET /tasks/ HTTP/1.1\r\n\r\n", &routes),
+            Some(String::from("308 Permanent Redirect /tasks"))
+        );
+        assert_eq!(location(&remove, "GET / HTTP/1.1\r\n\r\n", &routes), None);
+    }
+
+    #[test]
+    fn test_location_https() {
+        let rule = RedirectRule::Https {
+            host: None,
+            port: None,
+        };
+
+        assert_eq!(
+            location(
+                &rule,
+                "GET /a?b=c HTTP/1.1\r\nHost: example.com:8080\r\n\r\n",
+                &[]
+            ),
+            Some(String::from(
+                "308 Permanent Redirect https://example.com/a?b=c"
+            ))
+        );
+        assert_eq!(
+            location(
+                &rule,
+                "GET / HTTP/1.1\r\nHost: example.com\r\nX-Forwarded-Proto: https\r\n\r\n",
+                &[]
+            ),
+            None
+        );
+        assert_eq!(location(&rule, "GET / HTTP/1.1\r\n\r\n", &[]), None);
+
+        for host in [
+            "evil.com/x?",
+            "evil.com@",
+            "evil.com:80x",
+            "[::1",
+            "[]",
+            ":443",
+            "",
+        ] {
+            assert_eq!(
+                location(
+                    &rule,
+                    &format!("GET /path HTTP/1.1\r\nHost: {}\r\n\r\n", host),
+                    &[]
+                ),
+                None
+            );
+        }
+
+        let rule = RedirectRule::Https {
+            host: None,
+            port: Some(8443),
+        };
+
+        assert_eq!(
+            location(&rule, "GET / HTTP/1.1\r\nHost: [::1]:8080\r\n\r\n", &[]),
+            Some(String::from("308 Permanent Redirect https://[::1]:8443/"))
+        );
+
+        let rule = RedirectRule::Https {
+            host: Some(String::from("www.example.com")),
+            port: None,
+        };
+
+        assert_eq!(
+            location(&rule, "GET /a HTTP/1.1\r\nHost: evil.com/x?\r\n\r\n", &[]),
+            Some(String::from(
+                "308 Permanent Redirect https://www.example.com/a"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_host_name() {
+        assert_eq!(RedirectRule::host_name("example.com"), Some("example.com"));
+        assert_eq!(
+            RedirectRule::host_name("example.com:8080"),
+            Some("example.com")
+        );
+        assert_eq!(RedirectRule::host_name("[::1]:8080"), Some("[::1]"));
+        assert_eq!(RedirectRule::host_name("127.0.0.1"), Some("127.0.0.1"));
+        assert_eq!(RedirectRule::host_name("evil.com/x?"), None);
+        assert_eq!(RedirectRule::host_name("example.com:123456"), None);
+        assert_eq!(RedirectRule::host_name("[::1]x"), None);
+    }
+}
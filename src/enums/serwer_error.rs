@@ -1,3 +1,4 @@
+use crate::StatusCode;
 use std::{fmt, io, num, string};
 
 #[derive(Debug)]
@@ -14,14 +15,59 @@ pub enum SerwerError {
     HeaderMissingTailingCRLF(String),
     InvalidHeader(String),
     InvalidHeaderCharacters(String),
+    InvalidHeaderValue(String),
+    MissingHeader(String),
+    InvalidDate(String),
     InvalidCookie(String),
     InvalidCookieCharacters(String),
     DecodeError(String),
+    UnexpectedContentType(String),
+    UnsupportedEncoding(String),
+    MissingFormField(String),
+    InvalidMultipart(String),
+    PartTooLarge(String),
+    BodyTooLarge(String),
+    InvalidJson(String),
+    DeserializeError(String),
+    MissingState(String),
+    MissingExtension(String),
+    InvalidRedirect(String),
+    InvalidCookieAttribute(String),
+    InvalidSecretKey(String),
+    MissingSecretKey(String),
+    InvalidMount(String),
+    MissingRoute(String),
     IoError(io::Error),
     ParseIntError(num::ParseIntError),
     FromUtf8Error(string::FromUtf8Error),
 }
 
+impl SerwerError {
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            SerwerError::UnexpectedContentType(_) | SerwerError::UnsupportedEncoding(_) => {
+                StatusCode::UnsupportedMediaType
+            }
+            SerwerError::PartTooLarge(_) | SerwerError::BodyTooLarge(_) => {
+                StatusCode::PayloadTooLarge
+            }
+            SerwerError::MissingFormField(_) | SerwerError::DeserializeError(_) => {
+                StatusCode::UnprocessableEntity
+            }
+            SerwerError::MissingState(_)
+            | SerwerError::MissingExtension(_)
+            | SerwerError::InvalidRedirect(_)
+            | SerwerError::InvalidCookieAttribute(_)
+            | SerwerError::InvalidSecretKey(_)
+            | SerwerError::MissingSecretKey(_)
+            | SerwerError::InvalidMount(_)
+            | SerwerError::MissingRoute(_)
+            | SerwerError::IoError(_) => StatusCode::InternalServerError,
+            _ => StatusCode::BadRequest,
+        }
+    }
+}
+
 impl From<io::Error> for SerwerError {
     fn from(error: io::Error) -> Self {
         SerwerError::IoError(error)
@@ -75,11 +121,52 @@ impl fmt::Display for SerwerError {
             SerwerError::InvalidHeaderCharacters(header) => {
                 write!(f, r#"Invalid header characters: "{}""#, header)
             }
+            SerwerError::InvalidHeaderValue(value) => {
+                write!(f, r#"Invalid header value: "{}""#, value)
+            }
+            SerwerError::MissingHeader(header) => {
+                write!(f, r#"Missing header: "{}""#, header)
+            }
+            SerwerError::InvalidDate(date) => write!(f, r#"Invalid date: "{}""#, date),
             SerwerError::InvalidCookie(cookie) => write!(f, r#"Invalid cookie: "{}""#, cookie),
             SerwerError::InvalidCookieCharacters(cookie) => {
                 write!(f, r#"Invalid cookie characters: "{}""#, cookie)
             }
             SerwerError::DecodeError(string) => write!(f, r#"Decode error: "{}""#, string),
+            SerwerError::UnexpectedContentType(content_type) => {
+                write!(f, r#"Unexpected content type: "{}""#, content_type)
+            }
+            SerwerError::UnsupportedEncoding(encoding) => {
+                write!(f, r#"Unsupported encoding: "{}""#, encoding)
+            }
+            SerwerError::MissingFormField(field) => {
+                write!(f, r#"Missing form field: "{}""#, field)
+            }
+            SerwerError::InvalidMultipart(reason) => {
+                write!(f, r#"Invalid multipart: "{}""#, reason)
+            }
+            SerwerError::PartTooLarge(name) => write!(f, r#"Part too large: "{}""#, name),
+            SerwerError::BodyTooLarge(limit) => write!(f, r#"Body too large: "{}""#, limit),
+            SerwerError::InvalidJson(error) => write!(f, "Invalid JSON: {}", error),
+            SerwerError::DeserializeError(error) => write!(f, "Deserialize error: {}", error),
+            SerwerError::MissingState(state) => write!(f, r#"Missing state: "{}""#, state),
+            SerwerError::MissingExtension(extension) => {
+                write!(f, r#"Missing extension: "{}""#, extension)
+            }
+            SerwerError::InvalidRedirect(location) => {
+                write!(f, r#"Invalid redirect: "{}""#, location)
+            }
+            SerwerError::InvalidCookieAttribute(attribute) => {
+                write!(f, r#"Invalid cookie attribute: "{}""#, attribute)
+            }
+            SerwerError::InvalidSecretKey(length) => {
+                write!(f, r#"Invalid secret key length: "{}""#, length)
+            }
+            SerwerError::MissingSecretKey(reason) => {
+                write!(f, r#"Missing secret key: "{}""#, reason)
+            }
+            SerwerError::InvalidMount(mount) => write!(f, r#"Invalid mount: "{}""#, mount),
+            SerwerError::MissingRoute(route) => write!(f, r#"Missing route: "{}""#, route),
             SerwerError::IoError(error) => write!(f, "IO error: {}", error),
             SerwerError::ParseIntError(error) => write!(f, "Parse int error: {}", error),
             SerwerError::FromUtf8Error(error) => write!(f, "From utf8 error: {}", error),
@@ -114,6 +201,66 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_status_code() {
+        assert_eq!(
+            SerwerError::InvalidQueryParam(String::from("=1")).status_code(),
+            StatusCode::BadRequest
+        );
+        assert_eq!(
+            SerwerError::InvalidJson(String::from("error")).status_code(),
+            StatusCode::BadRequest
+        );
+        assert_eq!(
+            SerwerError::DeserializeError(String::from("error")).status_code(),
+            StatusCode::UnprocessableEntity
+        );
+        assert_eq!(
+            SerwerError::UnexpectedContentType(String::from("text/plain")).status_code(),
+            StatusCode::UnsupportedMediaType
+        );
+        assert_eq!(
+            SerwerError::UnsupportedEncoding(String::from("compress")).status_code(),
+            StatusCode::UnsupportedMediaType
+        );
+        assert_eq!(
+            SerwerError::PartTooLarge(String::from("file")).status_code(),
+            StatusCode::PayloadTooLarge
+        );
+        assert_eq!(
+            SerwerError::BodyTooLarge(String::from("1024")).status_code(),
+            StatusCode::PayloadTooLarge
+        );
+        assert_eq!(
+            SerwerError::MissingState(String::from("u32")).status_code(),
+            StatusCode::InternalServerError
+        );
+        assert_eq!(
+            SerwerError::InvalidRedirect(String::from("")).status_code(),
+            StatusCode::InternalServerError
+        );
+        assert_eq!(
+            SerwerError::InvalidCookieAttribute(String::from("")).status_code(),
+            StatusCode::InternalServerError
+        );
+        assert_eq!(
+            SerwerError::InvalidSecretKey(String::from("")).status_code(),
+            StatusCode::InternalServerError
+        );
+        assert_eq!(
+            SerwerError::MissingSecretKey(String::from("")).status_code(),
+            StatusCode::InternalServerError
+        );
+        assert_eq!(
+            SerwerError::InvalidMount(String::from("/static")).status_code(),
+            StatusCode::InternalServerError
+        );
+        assert_eq!(
+            SerwerError::MissingRoute(String::from("GET /")).status_code(),
+            StatusCode::InternalServerError
+        );
+    }
+
     #[test]
     fn test_display() {
         assert_eq!(
@@ -176,6 +323,21 @@ mod tests {
             r#"Invalid header characters: "header""#
         );
 
+        assert_eq!(
+            SerwerError::InvalidHeaderValue(String::from("value")).to_string(),
+            r#"Invalid header value: "value""#
+        );
+
+        assert_eq!(
+            SerwerError::MissingHeader(String::from("header")).to_string(),
+            r#"Missing header: "header""#
+        );
+
+        assert_eq!(
+            SerwerError::InvalidDate(String::from("date")).to_string(),
+            r#"Invalid date: "date""#
+        );
+
         assert_eq!(
             SerwerError::InvalidCookie(String::from("cookie")).to_string(),
             r#"Invalid cookie: "cookie""#
@@ -191,6 +353,86 @@ mod tests {
             r#"Decode error: "string""#
         );
 
+        assert_eq!(
+            SerwerError::UnexpectedContentType(String::from("text/plain")).to_string(),
+            r#"Unexpected content type: "text/plain""#
+        );
+
+        assert_eq!(
+            SerwerError::UnsupportedEncoding(String::from("compress")).to_string(),
+            r#"Unsupported encoding: "compress""#
+        );
+
+        assert_eq!(
+            SerwerError::MissingFormField(String::from("field")).to_string(),
+            r#"Missing form field: "field""#
+        );
+
+        assert_eq!(
+            SerwerError::InvalidMultipart(String::from("reason")).to_string(),
+            r#"Invalid multipart: "reason""#
+        );
+
+        assert_eq!(
+            SerwerError::PartTooLarge(String::from("name")).to_string(),
+            r#"Part too large: "name""#
+        );
+
+        assert_eq!(
+            SerwerError::BodyTooLarge(String::from("1024")).to_string(),
+            r#"Body too large: "1024""#
+        );
+
+        assert_eq!(
+            SerwerError::InvalidJson(String::from("EOF while parsing a value")).to_string(),
+            "Invalid JSON: EOF while parsing a value"
+        );
+
+        assert_eq!(
+            SerwerError::DeserializeError(String::from("missing field `name`")).to_string(),
+            "Deserialize error: missing field `name`"
+        );
+
+        assert_eq!(
+            SerwerError::MissingState(String::from("u32")).to_string(),
+            r#"Missing state: "u32""#
+        );
+
+        assert_eq!(
+            SerwerError::MissingExtension(String::from("u32")).to_string(),
+            r#"Missing extension: "u32""#
+        );
+
+        assert_eq!(
+            SerwerError::InvalidRedirect(String::from("")).to_string(),
+            r#"Invalid redirect: """#
+        );
+
+        assert_eq!(
+            SerwerError::InvalidCookieAttribute(String::from("")).to_string(),
+            r#"Invalid cookie attribute: """#
+        );
+
+        assert_eq!(
+            SerwerError::InvalidSecretKey(String::from("")).to_string(),
+            r#"Invalid secret key length: """#
+        );
+
+        assert_eq!(
+            SerwerError::MissingSecretKey(String::from("")).to_string(),
+            r#"Missing secret key: """#
+        );
+
+        assert_eq!(
+            SerwerError::InvalidMount(String::from("/static")).to_string(),
+            r#"Invalid mount: "/static""#
+        );
+
+        assert_eq!(
+            SerwerError::MissingRoute(String::from("GET /")).to_string(),
+            r#"Missing route: "GET /""#
+        );
+
         let mut buffer = BufReader::new(stream_from_bytes(&[255]));
         let string = &mut String::from("");
 
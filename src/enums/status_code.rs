@@ -64,6 +64,19 @@ pub enum StatusCode {
     NetworkAuthenticationRequired = 511,
 }
 
+impl StatusCode {
+    pub fn is_redirect(&self) -> bool {
+        matches!(
+            self,
+            StatusCode::MovedPermanently
+                | StatusCode::Found
+                | StatusCode::SeeOther
+                | StatusCode::TemporaryRedirect
+                | StatusCode::PermanentRedirect
+        )
+    }
+}
+
 impl ToString for StatusCode {
     fn to_string(&self) -> String {
         match self {
@@ -314,4 +327,12 @@ mod tests {
             "511 Network Authentication Required"
         );
     }
+
+    #[test]
+    fn is_redirect() {
+        assert!(StatusCode::MovedPermanently.is_redirect());
+        assert!(StatusCode::PermanentRedirect.is_redirect());
+        assert!(!StatusCode::NotModified.is_redirect());
+        assert!(!StatusCode::OK.is_redirect());
+    }
 }
@@ -1,10 +1,25 @@
 mod enums;
 mod structs;
+mod traits;
 mod utils;
 
 use enums::*;
 use structs::*;
 use utils::*;
 
-pub use enums::StatusCode;
-pub use structs::{Cookie, Data, Serwer};
+pub use enums::{
+    Body, ByteRange, ContentDecoder, ContentEncoder, ContentEncoding, CookieEncoding,
+    CookiePriority, Credentials, ETagMode, EntityTagList, Method, RangeCondition, RedirectRule,
+    SameSite, SerwerError, StatusCode, TrailingSlash,
+};
+pub use structs::{
+    Accept, AcceptCharset, AcceptEncoding, AcceptLanguage, Age, Authorization, CacheControl,
+    CompressionConfig, ContentType, Cookie, Cookies, Data, Date, DecompressionConfig, ETag,
+    EntityTag, Expires, Extension, Extensions, Form, Header, HttpDate, IfMatch, IfModifiedSince,
+    IfNoneMatch, IfRange, IfUnmodifiedSince, LastModified, MediaType, Multipart, MultipartConfig,
+    NormalizationConfig, Part, QualityItem, Range, Request, Response, ResponseCache, RewriteRule,
+    Serwer, StaticFiles,
+};
+#[cfg(feature = "serde")]
+pub use structs::{Json, PathParams, Query, SerdeForm};
+pub use traits::{FromForm, FromRequest, Handler, IntoResponse, TypedHeader};
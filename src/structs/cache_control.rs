@@ -0,0 +1,299 @@
+use crate::{is_token, split_quoted, unquote, SerwerError, TypedHeader};
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CacheControl {
+    public: bool,
+    private: bool,
+    no_cache: bool,
+    no_store: bool,
+    no_transform: bool,
+    must_revalidate: bool,
+    proxy_revalidate: bool,
+    immutable: bool,
+    max_age: Option<Duration>,
+    s_maxage: Option<Duration>,
+    stale_while_revalidate: Option<Duration>,
+    stale_if_error: Option<Duration>,
+    extensions: Vec<(String, Option<String>)>,
+}
+
+impl CacheControl {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_string(string: &str) -> Result<Self, SerwerError> {
+        let mut cache_control = Self::new();
+
+        for directive in split_quoted(string, ',') {
+            if directive.is_empty() {
+                continue;
+            }
+
+            let (name, value) = match directive.split_once('=') {
+                Some((name, value)) => (name.trim().to_lowercase(), Some(unquote(value.trim()))),
+                None => (directive.to_lowercase(), None),
+            };
+
+            let seconds = || -> Result<Duration, SerwerError> {
+                value
+                    .as_ref()
+                    .and_then(|value| value.parse().ok())
+                    .map(Duration::from_secs)
+                    .ok_or(SerwerError::InvalidHeaderValue(String::from(string)))
+            };
+
+            match name.as_str() {
+                "public" => cache_control.public = true,
+                "private" => cache_control.private = true,
+                "no-cache" => cache_control.no_cache = true,
+                "no-store" => cache_control.no_store = true,
+                "no-transform" => cache_control.no_transform = true,
+                "must-revalidate" => cache_control.must_revalidate = true,
+                "proxy-revalidate" => cache_control.proxy_revalidate = true,
+                "immutable" => cache_control.immutable = true,
+                "max-age" => cache_control.max_age = Some(seconds()?),
+                "s-maxage" => cache_control.s_maxage = Some(seconds()?),
+                "stale-while-revalidate" => cache_control.stale_while_revalidate = Some(seconds()?),
+                "stale-if-error" => cache_control.stale_if_error = Some(seconds()?),
+                _ => cache_control.extensions.push((name, value)),
+            }
+        }
+
+        Ok(cache_control)
+    }
+
+    pub fn is_public(&self) -> bool {
+        self.public
+    }
+
+    pub fn is_private(&self) -> bool {
+        self.private
+    }
+
+    pub fn is_no_cache(&self) -> bool {
+        self.no_cache
+    }
+
+    pub fn is_no_store(&self) -> bool {
+        self.no_store
+    }
+
+    pub fn is_no_transform(&self) -> bool {
+        self.no_transform
+    }
+
+    pub fn is_must_revalidate(&self) -> bool {
+        self.must_revalidate
+    }
+
+    pub fn is_proxy_revalidate(&self) -> bool {
+        self.proxy_revalidate
+    }
+
+    pub fn is_immutable(&self) -> bool {
+        self.immutable
+    }
+
+    pub fn max_age(&self) -> Option<Duration> {
+        self.max_age
+    }
+
+    pub fn s_maxage(&self) -> Option<Duration> {
+        self.s_maxage
+    }
+
+    pub fn stale_while_revalidate(&self) -> Option<Duration> {
+        self.stale_while_revalidate
+    }
+
+    pub fn stale_if_error(&self) -> Option<Duration> {
+        self.stale_if_error
+    }
+
+    pub fn extension(&self, name: &str) -> Option<Option<&String>> {
+        let name = name.to_lowercase();
+
+        self.extensions
+            .iter()
+            .find(|(extension_name, _)| extension_name == &name)
+            .map(|(_, value)| value.as_ref())
+    }
+
+    pub fn set_public(mut self, public: bool) -> Self {
+        self.public = public;
+        self
+    }
+
+    pub fn set_private(mut self, private: bool) -> Self {
+        self.private = private;
+        self
+    }
+
+    pub fn set_no_cache(mut self, no_cache: bool) -> Self {
+        self.no_cache = no_cache;
+        self
+    }
+
+    pub fn set_no_store(mut self, no_store: bool) -> Self {
+        self.no_store = no_store;
+        self
+    }
+
+    pub fn set_no_transform(mut self, no_transform: bool) -> Self {
+        self.no_transform = no_transform;
+        self
+    }
+
+    pub fn set_must_revalidate(mut self, must_revalidate: bool) -> Self {
+        self.must_revalidate = must_revalidate;
+        self
+    }
+
+    pub fn set_proxy_revalidate(mut self, proxy_revalidate: bool) -> Self {
+        self.proxy_revalidate = proxy_revalidate;
+        self
+    }
+
+    pub fn set_immutable(mut self, immutable: bool) -> Self {
+        self.immutable = immutable;
+        self
+    }
+
+    pub fn set_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    pub fn set_s_maxage(mut self, s_maxage: Duration) -> Self {
+        self.s_maxage = Some(s_maxage);
+        self
+    }
+
+    pub fn set_stale_while_revalidate(mut self, stale_while_revalidate: Duration) -> Self {
+        self.stale_while_revalidate = Some(stale_while_revalidate);
+        self
+    }
+
+    pub fn set_stale_if_error(mut self, stale_if_error: Duration) -> Self {
+        self.stale_if_error = Some(stale_if_error);
+        self
+    }
+
+    pub fn set_extension(mut self, name: &str, value: Option<&str>) -> Self {
+        self.extensions
+            .push((name.to_lowercase(), value.map(String::from)));
+        self
+    }
+}
+
+impl TypedHeader for CacheControl {
+    fn name() -> &'static str {
+        "Cache-Control"
+    }
+
+    fn from_value(value: &str) -> Result<Self, SerwerError> {
+        Self::from_string(value)
+    }
+
+    fn to_value(&self) -> String {
+        let flags = [
+            (self.public, "public"),
+            (self.private, "private"),
+            (self.no_cache, "no-cache"),
+            (self.no_store, "no-store"),
+            (self.no_transform, "no-transform"),
+            (self.must_revalidate, "must-revalidate"),
+            (self.proxy_revalidate, "proxy-revalidate"),
+        ];
+        let durations = [
+            (self.max_age, "max-age"),
+            (self.s_maxage, "s-maxage"),
+            (self.stale_while_revalidate, "stale-while-revalidate"),
+            (self.stale_if_error, "stale-if-error"),
+        ];
+
+        let mut directives: Vec<String> = flags
+            .into_iter()
+            .filter(|(enabled, _)| *enabled)
+            .map(|(_, name)| String::from(name))
+            .collect();
+
+        directives.extend(durations.into_iter().filter_map(|(duration, name)| {
+            duration.map(|duration| format!("{}={}", name, duration.as_secs()))
+        }));
+
+        if self.immutable {
+            directives.push(String::from("immutable"));
+        }
+
+        directives.extend(self.extensions.iter().map(|(name, value)| match value {
+            Some(value) if is_token(value) => format!("{}={}", name, value),
+            Some(value) => format!("{}=\"{}\"", name, value.replace('"', "\\\"")),
+            None => name.clone(),
+        }));
+
+        directives.join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_string() {
+        let cache_control =
+            CacheControl::from_string("Public, max-age=3600, s-maxage=\"60\", immutable, foo=bar")
+                .unwrap();
+
+        assert!(cache_control.is_public());
+        assert!(!cache_control.is_private());
+        assert!(cache_control.is_immutable());
+        assert_eq!(cache_control.max_age(), Some(Duration::from_secs(3600)));
+        assert_eq!(cache_control.s_maxage(), Some(Duration::from_secs(60)));
+        assert_eq!(
+            cache_control.extension("FOO"),
+            Some(Some(&String::from("bar")))
+        );
+        assert_eq!(cache_control.extension("bar"), None);
+
+        let cache_control =
+            CacheControl::from_string("no-store, no-cache, only-if-cached").unwrap();
+
+        assert!(cache_control.is_no_store());
+        assert!(cache_control.is_no_cache());
+        assert_eq!(cache_control.extension("only-if-cached"), Some(None));
+
+        assert!(matches!(
+            CacheControl::from_string("max-age=soon"),
+            Err(SerwerError::InvalidHeaderValue(error_string)) if &error_string == "max-age=soon"
+        ));
+        assert!(matches!(
+            CacheControl::from_string("max-age"),
+            Err(SerwerError::InvalidHeaderValue(error_string)) if &error_string == "max-age"
+        ));
+    }
+
+    #[test]
+    fn test_to_value() {
+        let cache_control = CacheControl::new()
+            .set_private(true)
+            .set_must_revalidate(true)
+            .set_max_age(Duration::from_secs(60))
+            .set_stale_while_revalidate(Duration::from_secs(30))
+            .set_extension("community", Some("UCI Irvine"));
+
+        assert_eq!(
+            cache_control.to_value(),
+            "private, must-revalidate, max-age=60, stale-while-revalidate=30, community=\"UCI Irvine\""
+        );
+        assert_eq!(
+            CacheControl::from_value(&cache_control.to_value()).unwrap(),
+            cache_control
+        );
+        assert_eq!(CacheControl::new().to_value(), "");
+        assert_eq!(CacheControl::name(), "Cache-Control");
+    }
+}
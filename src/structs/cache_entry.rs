@@ -0,0 +1,118 @@
+use crate::{Age, Request, Response};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone)]
+pub struct CacheEntry {
+    response: Response,
+    vary: Vec<(String, Option<String>)>,
+    stored_at: Instant,
+    expires_at: Instant,
+    last_used: Instant,
+}
+
+impl CacheEntry {
+    pub fn new(
+        response: Response,
+        vary: Vec<(String, Option<String>)>,
+        ttl: Duration,
+    ) -> Option<Self> {
+        let now = Instant::now();
+
+        Some(Self {
+            response,
+            vary,
+            stored_at: now,
+            expires_at: now.checked_add(ttl)?,
+            last_used: now,
+        })
+    }
+
+    pub fn vary(&self) -> &Vec<(String, Option<String>)> {
+        &self.vary
+    }
+
+    pub fn last_used(&self) -> Instant {
+        self.last_used
+    }
+
+    pub fn size(&self) -> u64 {
+        self.response.body().len()
+    }
+
+    pub fn is_fresh(&self) -> bool {
+        Instant::now() < self.expires_at
+    }
+
+    pub fn matches(&self, request: &Request) -> bool {
+        self.vary
+            .iter()
+            .all(|(name, value)| &request.header_joined(name) == value)
+    }
+
+    pub fn response(&mut self) -> Response {
+        self.last_used = Instant::now();
+
+        let mut response = self.response.clone();
+        response.set_typed_header(Age(Duration::from_secs(self.stored_at.elapsed().as_secs())));
+
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{request_from_bytes, Version};
+
+    #[test]
+    fn test_new() {
+        let mut response = Response::new(&Version::HTTP_1_1);
+        response.set_body("Hello");
+
+        let mut entry = CacheEntry::new(response, vec![], Duration::from_secs(60)).unwrap();
+
+        assert!(entry.is_fresh());
+        assert_eq!(entry.size(), 5);
+        assert_eq!(entry.response().header("Age"), Some(String::from("0")));
+    }
+
+    #[test]
+    fn test_is_fresh() {
+        let entry =
+            CacheEntry::new(Response::new(&Version::HTTP_1_1), vec![], Duration::ZERO).unwrap();
+
+        assert!(!entry.is_fresh());
+    }
+
+    #[test]
+    fn test_new_overflow() {
+        assert!(
+            CacheEntry::new(Response::new(&Version::HTTP_1_1), vec![], Duration::MAX).is_none()
+        );
+    }
+
+    #[test]
+    fn test_matches() {
+        let entry = CacheEntry::new(
+            Response::new(&Version::HTTP_1_1),
+            vec![
+                (String::from("accept-language"), Some(String::from("pl"))),
+                (String::from("authorization"), None),
+            ],
+            Duration::from_secs(60),
+        )
+        .unwrap();
+
+        let request = request_from_bytes(b"GET / HTTP/1.1\r\nAccept-Language: pl\r\n\r\n").unwrap();
+        assert!(entry.matches(&request));
+
+        let request = request_from_bytes(b"GET / HTTP/1.1\r\nAccept-Language: en\r\n\r\n").unwrap();
+        assert!(!entry.matches(&request));
+
+        let request = request_from_bytes(
+            b"GET / HTTP/1.1\r\nAccept-Language: pl\r\nAuthorization: Basic YTpi\r\n\r\n",
+        )
+        .unwrap();
+        assert!(!entry.matches(&request));
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{ContentEncoding, MediaType};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompressionConfig {
+    min_size: usize,
+    max_file_size: u64,
+    encodings: Vec<ContentEncoding>,
+    content_types: Vec<MediaType>,
+}
+
+impl CompressionConfig {
+    pub fn new() -> Self {
+        Self {
+            min_size: 1024,
+            max_file_size: 1024 * 1024,
+            encodings: ContentEncoding::supported(),
+            content_types: vec![
+                MediaType::new("text", "*"),
+                MediaType::new("application", "json"),
+                MediaType::new("application", "javascript"),
+                MediaType::new("application", "xml"),
+                MediaType::new("image", "svg+xml"),
+            ],
+        }
+    }
+
+    pub fn min_size(&self) -> usize {
+        self.min_size
+    }
+
+    pub fn max_file_size(&self) -> u64 {
+        self.max_file_size
+    }
+
+    pub fn encodings(&self) -> &Vec<ContentEncoding> {
+        &self.encodings
+    }
+
+    pub fn content_types(&self) -> &Vec<MediaType> {
+        &self.content_types
+    }
+
+    pub fn is_compressible(&self, media_type: &MediaType) -> bool {
+        media_type.sub_type().ends_with("+json")
+            || media_type.sub_type().ends_with("+xml")
+            || self
+                .content_types
+                .iter()
+                .any(|content_type| content_type.matches(media_type))
+    }
+
+    pub fn set_min_size(mut self, min_size: usize) -> Self {
+        self.min_size = min_size;
+        self
+    }
+
+    pub fn set_max_file_size(mut self, max_file_size: u64) -> Self {
+        self.max_file_size = max_file_size;
+        self
+    }
+
+    pub fn set_encodings(mut self, encodings: &[ContentEncoding]) -> Self {
+        self.encodings = encodings
+            .iter()
+            .filter(|encoding| **encoding != ContentEncoding::Identity && encoding.is_supported())
+            .copied()
+            .collect();
+        self
+    }
+
+    pub fn set_content_types(mut self, content_types: &[MediaType]) -> Self {
+        self.content_types = content_types.to_vec();
+        self
+    }
+}
+
+impl Default for CompressionConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compression_config_builder() {
+        let config = CompressionConfig::new()
+            .set_min_size(256)
+            .set_max_file_size(4096)
+            .set_encodings(&[ContentEncoding::Identity, ContentEncoding::Gzip])
+            .set_content_types(&[MediaType::new("text", "html")]);
+
+        assert_eq!(config.min_size(), 256);
+        assert_eq!(config.max_file_size(), 4096);
+        assert_eq!(
+            config.encodings(),
+            &ContentEncoding::supported()
+                .into_iter()
+                .filter(|encoding| *encoding == ContentEncoding::Gzip)
+                .collect::<Vec<ContentEncoding>>()
+        );
+        assert_eq!(
+            config.content_types(),
+            &vec![MediaType::new("text", "html")]
+        );
+    }
+
+    #[test]
+    fn test_is_compressible() {
+        let config = CompressionConfig::new();
+
+        assert!(config.is_compressible(&MediaType::new("text", "html")));
+        assert!(config.is_compressible(&MediaType::new("application", "json")));
+        assert!(config.is_compressible(&MediaType::new("application", "problem+json")));
+        assert!(config.is_compressible(&MediaType::new("application", "atom+xml")));
+        assert!(!config.is_compressible(&MediaType::new("image", "png")));
+        assert!(!config.is_compressible(&MediaType::new("application", "octet-stream")));
+    }
+}
@@ -0,0 +1,302 @@
+#[cfg(any(feature = "signed", feature = "private"))]
+use crate::SecretKey;
+use crate::{
+    CompressionConfig, CookieEncoding, Data, DecompressionConfig, ETagMode, NormalizationConfig,
+    Path, RedirectRule, ResponseCache, RewriteRule, States, StaticFiles,
+};
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    preserve_header_case: bool,
+    max_body_size: usize,
+    states: States,
+    compression: Option<CompressionConfig>,
+    decompression: Option<DecompressionConfig>,
+    mounts: Vec<(Path, StaticFiles)>,
+    etag_mode: Option<ETagMode>,
+    cache: Option<ResponseCache>,
+    redirects: Vec<RedirectRule>,
+    normalization: NormalizationConfig,
+    rewrites: Vec<RewriteRule>,
+    cookie_encoding: CookieEncoding,
+    #[cfg(any(feature = "signed", feature = "private"))]
+    secret_keys: Vec<SecretKey>,
+}
+
+impl Config {
+    pub fn new() -> Self {
+        Self {
+            preserve_header_case: false,
+            max_body_size: 64 * 1024 * 1024,
+            states: States::new(),
+            compression: None,
+            decompression: None,
+            mounts: vec![],
+            etag_mode: None,
+            cache: None,
+            redirects: vec![],
+            normalization: NormalizationConfig::new(),
+            rewrites: vec![],
+            cookie_encoding: CookieEncoding::Raw,
+            #[cfg(any(feature = "signed", feature = "private"))]
+            secret_keys: vec![],
+        }
+    }
+
+    pub fn preserve_header_case(&self) -> bool {
+        self.preserve_header_case
+    }
+
+    pub fn set_preserve_header_case(&mut self, preserve_header_case: bool) {
+        self.preserve_header_case = preserve_header_case;
+    }
+
+    pub fn max_body_size(&self) -> usize {
+        self.max_body_size
+    }
+
+    pub fn set_max_body_size(&mut self, max_body_size: usize) {
+        self.max_body_size = max_body_size;
+    }
+
+    pub fn states(&self) -> &States {
+        &self.states
+    }
+
+    pub fn set_state<T: Send + Sync + 'static>(&mut self, data: Data<T>) {
+        self.states.set_state(data);
+    }
+
+    pub fn compression(&self) -> Option<&CompressionConfig> {
+        self.compression.as_ref()
+    }
+
+    pub fn set_compression(&mut self, compression: CompressionConfig) {
+        self.compression = Some(compression);
+    }
+
+    pub fn decompression(&self) -> Option<&DecompressionConfig> {
+        self.decompression.as_ref()
+    }
+
+    pub fn set_decompression(&mut self, decompression: DecompressionConfig) {
+        self.decompression = Some(decompression);
+    }
+
+    pub fn mounts(&self) -> &Vec<(Path, StaticFiles)> {
+        &self.mounts
+    }
+
+    pub fn add_mount(&mut self, prefix: Path, static_files: StaticFiles) {
+        self.mounts.push((prefix, static_files));
+    }
+
+    pub fn etag_mode(&self) -> Option<ETagMode> {
+        self.etag_mode
+    }
+
+    pub fn set_etag_mode(&mut self, etag_mode: ETagMode) {
+        self.etag_mode = Some(etag_mode);
+    }
+
+    pub fn cache(&self) -> Option<&ResponseCache> {
+        self.cache.as_ref()
+    }
+
+    pub fn set_cache(&mut self, cache: ResponseCache) {
+        self.cache = Some(cache);
+    }
+
+    pub fn redirects(&self) -> &Vec<RedirectRule> {
+        &self.redirects
+    }
+
+    pub fn add_redirect(&mut self, redirect: RedirectRule) {
+        self.redirects.push(redirect);
+    }
+
+    pub fn normalization(&self) -> &NormalizationConfig {
+        &self.normalization
+    }
+
+    pub fn set_normalization(&mut self, normalization: NormalizationConfig) {
+        self.normalization = normalization;
+    }
+
+    pub fn rewrites(&self) -> &Vec<RewriteRule> {
+        &self.rewrites
+    }
+
+    pub fn add_rewrite(&mut self, rewrite: RewriteRule) {
+        self.rewrites.push(rewrite);
+    }
+
+    pub fn cookie_encoding(&self) -> CookieEncoding {
+        self.cookie_encoding
+    }
+
+    pub fn set_cookie_encoding(&mut self, cookie_encoding: CookieEncoding) {
+        self.cookie_encoding = cookie_encoding;
+    }
+
+    #[cfg(any(feature = "signed", feature = "private"))]
+    pub fn secret_keys(&self) -> &Vec<SecretKey> {
+        &self.secret_keys
+    }
+
+    #[cfg(any(feature = "signed", feature = "private"))]
+    pub fn set_secret_key(&mut self, secret_key: SecretKey) {
+        match self.secret_keys.first_mut() {
+            Some(current) => *current = secret_key,
+            None => self.secret_keys.push(secret_key),
+        }
+    }
+
+    #[cfg(any(feature = "signed", feature = "private"))]
+    pub fn add_previous_secret_key(&mut self, secret_key: SecretKey) {
+        self.secret_keys.push(secret_key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let config = Config::new();
+        assert!(!config.preserve_header_case());
+        assert_eq!(config.max_body_size(), 64 * 1024 * 1024);
+        assert_eq!(config.compression(), None);
+        assert_eq!(config.decompression(), None);
+        assert!(config.mounts().is_empty());
+        assert_eq!(config.etag_mode(), None);
+        assert!(config.cache().is_none());
+        assert!(config.redirects().is_empty());
+        assert_eq!(config.normalization(), &NormalizationConfig::new());
+        assert!(config.rewrites().is_empty());
+        assert_eq!(config.cookie_encoding(), CookieEncoding::Raw);
+    }
+
+    #[test]
+    fn test_set_preserve_header_case() {
+        let mut config = Config::new();
+        config.set_preserve_header_case(true);
+        assert!(config.preserve_header_case());
+    }
+
+    #[test]
+    fn test_set_max_body_size() {
+        let mut config = Config::new();
+        config.set_max_body_size(1024);
+        assert_eq!(config.max_body_size(), 1024);
+    }
+
+    #[test]
+    fn test_set_state() {
+        let mut config = Config::new();
+        assert!(config.states().state::<u32>().is_none());
+
+        config.set_state(Data::new(42u32));
+        assert_eq!(*config.states().state::<u32>().unwrap().read(), 42);
+    }
+
+    #[test]
+    fn test_set_compression() {
+        let mut config = Config::new();
+        config.set_compression(CompressionConfig::new().set_min_size(0));
+        assert_eq!(config.compression().unwrap().min_size(), 0);
+    }
+
+    #[test]
+    fn test_set_decompression() {
+        let mut config = Config::new();
+        config.set_decompression(DecompressionConfig::new().set_max_size(1024));
+        assert_eq!(config.decompression().unwrap().max_size(), 1024);
+    }
+
+    #[test]
+    fn test_add_mount() {
+        let mut config = Config::new();
+        config.add_mount(
+            Path::from_string("/static").unwrap(),
+            StaticFiles::new("static"),
+        );
+        assert_eq!(
+            config.mounts(),
+            &vec![(
+                Path::from_string("/static").unwrap(),
+                StaticFiles::new("static")
+            )]
+        );
+    }
+
+    #[test]
+    fn test_set_etag_mode() {
+        let mut config = Config::new();
+        config.set_etag_mode(ETagMode::Weak);
+        assert_eq!(config.etag_mode(), Some(ETagMode::Weak));
+    }
+
+    #[test]
+    fn test_set_cache() {
+        let mut config = Config::new();
+        config.set_cache(ResponseCache::new().set_max_entries(10));
+        assert_eq!(config.cache().unwrap().max_entries(), 10);
+    }
+
+    #[test]
+    fn test_add_redirect() {
+        let mut config = Config::new();
+        config.add_redirect(RedirectRule::Https {
+            host: None,
+            port: None,
+        });
+        assert_eq!(
+            config.redirects(),
+            &vec![RedirectRule::Https {
+                host: None,
+                port: None
+            }]
+        );
+    }
+
+    #[test]
+    fn test_set_normalization() {
+        let mut config = Config::new();
+        config.set_normalization(NormalizationConfig::new().set_case_folding(true));
+        assert!(config.normalization().case_folding());
+    }
+
+    #[test]
+    fn test_add_rewrite() {
+        let mut config = Config::new();
+        config.add_rewrite(RewriteRule::new("/u/<id>", "/user/<id>").unwrap());
+        assert_eq!(config.rewrites()[0].to(), "/user/<id>");
+    }
+
+    #[test]
+    fn test_set_cookie_encoding() {
+        let mut config = Config::new();
+        config.set_cookie_encoding(CookieEncoding::Base64Url);
+        assert_eq!(config.cookie_encoding(), CookieEncoding::Base64Url);
+    }
+
+    #[cfg(any(feature = "signed", feature = "private"))]
+    #[test]
+    fn test_set_secret_key() {
+        let first = SecretKey::from_secret(&[1; 32]).unwrap();
+        let second = SecretKey::from_secret(&[2; 32]).unwrap();
+        let third = SecretKey::from_secret(&[3; 32]).unwrap();
+
+        let mut config = Config::new();
+        assert!(config.secret_keys().is_empty());
+
+        config.set_secret_key(first.clone());
+        config.add_previous_secret_key(second.clone());
+        assert_eq!(config.secret_keys(), &vec![first, second.clone()]);
+
+        config.set_secret_key(third.clone());
+        assert_eq!(config.secret_keys(), &vec![third, second]);
+    }
+}
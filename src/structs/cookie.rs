@@ -1,4 +1,5 @@
-use crate::SerwerError;
+use crate::{CookieEncoding, CookiePriority, HttpDate, SameSite, SerwerError};
+use std::time::Duration;
 
 const NAME_ALLOWED_CHARACTERS: &str =
     "!#$%&'*+-.0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ^_`abcdefghijklmnopqrstuvwxyz|~";
@@ -6,17 +7,23 @@ const NAME_ALLOWED_CHARACTERS: &str =
 const VALUE_ALLOWED_CHARACTERS: &str =
     "!#$%&'()*+-./0123456789:<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[]^_`abcdefghijklmnopqrstuvwxyz{|}~";
 
+const DOMAIN_ALLOWED_CHARACTERS: &str =
+    "-.0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Cookie {
     name: String,
     value: String,
-    expires: Option<String>,
-    max_age: Option<u64>,
+    expires: Option<HttpDate>,
+    max_age: Option<Duration>,
     domain: Option<String>,
     path: Option<String>,
     secure: bool,
     http_only: bool,
-    same_site: Option<String>,
+    same_site: Option<SameSite>,
+    partitioned: bool,
+    priority: Option<CookiePriority>,
+    encoding: Option<CookieEncoding>,
 }
 
 impl Cookie {
@@ -31,6 +38,9 @@ impl Cookie {
             secure: false,
             http_only: false,
             same_site: None,
+            partitioned: false,
+            priority: None,
+            encoding: None,
         }
     }
 
@@ -70,6 +80,9 @@ impl Cookie {
             secure: false,
             http_only: false,
             same_site: None,
+            partitioned: false,
+            priority: None,
+            encoding: None,
         })
     }
 
@@ -81,12 +94,12 @@ impl Cookie {
         &self.value
     }
 
-    pub fn expires(&self) -> Option<&String> {
-        self.expires.as_ref()
+    pub fn expires(&self) -> Option<HttpDate> {
+        self.expires
     }
 
-    pub fn max_age(&self) -> Option<&u64> {
-        self.max_age.as_ref()
+    pub fn max_age(&self) -> Option<Duration> {
+        self.max_age
     }
 
     pub fn domain(&self) -> Option<&String> {
@@ -105,16 +118,28 @@ impl Cookie {
         self.http_only
     }
 
-    pub fn same_site(&self) -> Option<&String> {
-        self.same_site.as_ref()
+    pub fn same_site(&self) -> Option<SameSite> {
+        self.same_site
+    }
+
+    pub fn partitioned(&self) -> bool {
+        self.partitioned
+    }
+
+    pub fn priority(&self) -> Option<CookiePriority> {
+        self.priority
+    }
+
+    pub fn encoding(&self) -> Option<CookieEncoding> {
+        self.encoding
     }
 
-    pub fn set_expires(mut self, expires: &str) -> Self {
-        self.expires = Some(String::from(expires));
+    pub fn set_expires(mut self, expires: HttpDate) -> Self {
+        self.expires = Some(expires);
         self
     }
 
-    pub fn set_max_age(mut self, max_age: u64) -> Self {
+    pub fn set_max_age(mut self, max_age: Duration) -> Self {
         self.max_age = Some(max_age);
         self
     }
@@ -139,10 +164,89 @@ impl Cookie {
         self
     }
 
-    pub fn set_same_site(mut self, same_site: &str) -> Self {
-        self.same_site = Some(String::from(same_site));
+    pub fn set_same_site(mut self, same_site: SameSite) -> Self {
+        self.same_site = Some(same_site);
+        self
+    }
+
+    pub fn set_partitioned(mut self, partitioned: bool) -> Self {
+        self.partitioned = partitioned;
+        self
+    }
+
+    pub fn set_priority(mut self, priority: CookiePriority) -> Self {
+        self.priority = Some(priority);
         self
     }
+
+    pub fn set_encoding(mut self, encoding: CookieEncoding) -> Self {
+        self.encoding = Some(encoding);
+        self
+    }
+
+    pub fn validate(&self) -> Result<(), SerwerError> {
+        if let Some(domain) = &self.domain {
+            let labels = domain.strip_prefix('.').unwrap_or(domain);
+
+            if labels.is_empty()
+                || !labels
+                    .chars()
+                    .all(|c| DOMAIN_ALLOWED_CHARACTERS.contains(c))
+                || labels
+                    .split('.')
+                    .any(|label| label.is_empty() || label.starts_with('-') || label.ends_with('-'))
+            {
+                return Err(SerwerError::InvalidCookieAttribute(format!(
+                    "Domain={}",
+                    domain
+                )));
+            }
+        }
+
+        if let Some(path) = &self.path {
+            if !path.starts_with('/') || path.chars().any(|c| c.is_control() || c == ';') {
+                return Err(SerwerError::InvalidCookieAttribute(format!(
+                    "Path={}",
+                    path
+                )));
+            }
+        }
+
+        if self.same_site == Some(SameSite::None) && !self.secure {
+            return Err(SerwerError::InvalidCookieAttribute(String::from(
+                "SameSite=None",
+            )));
+        }
+
+        if self.partitioned && !self.secure {
+            return Err(SerwerError::InvalidCookieAttribute(String::from(
+                "Partitioned",
+            )));
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn encoded_value(&self) -> String {
+        self.encoding
+            .unwrap_or(CookieEncoding::Raw)
+            .encode(&self.value)
+    }
+
+    #[cfg(any(feature = "signed", feature = "private"))]
+    pub(crate) fn with_value(&self, value: String) -> Self {
+        let mut cookie = self.clone();
+        cookie.value = value;
+
+        cookie
+    }
+
+    pub(crate) fn decode(&self, encoding: CookieEncoding) -> Option<Self> {
+        let mut cookie = self.clone();
+        cookie.value = encoding.decode(&self.value).ok()?;
+
+        Some(cookie)
+    }
 }
 
 #[cfg(test)]
@@ -236,24 +340,89 @@ mod tests {
     #[test]
     fn test_cookie_builder() {
         let cookie = Cookie::new("id", "1")
-            .set_expires("Mon, 18 Dec 2023 06:11:00 GMT")
-            .set_max_age(86400)
+            .set_expires(HttpDate::from_unix_timestamp(1702879860))
+            .set_max_age(Duration::from_secs(86400))
             .set_domain("localhost")
             .set_path("/")
             .set_secure(true)
             .set_http_only(true)
-            .set_same_site("Strict");
+            .set_same_site(SameSite::Strict)
+            .set_partitioned(true)
+            .set_priority(CookiePriority::High);
 
         assert_eq!(cookie.name(), "id");
         assert_eq!(
-            cookie.expires,
-            Some(String::from("Mon, 18 Dec 2023 06:11:00 GMT"))
+            cookie.expires().unwrap().to_string(),
+            "Mon, 18 Dec 2023 06:11:00 GMT"
         );
-        assert_eq!(cookie.max_age(), Some(&86400));
+        assert_eq!(cookie.max_age(), Some(Duration::from_secs(86400)));
         assert_eq!(cookie.domain(), Some(&String::from("localhost")));
         assert_eq!(cookie.path(), Some(&String::from("/")));
         assert_eq!(cookie.secure(), true);
         assert_eq!(cookie.http_only(), true);
-        assert_eq!(cookie.same_site(), Some(&String::from("Strict")));
+        assert_eq!(cookie.same_site(), Some(SameSite::Strict));
+        assert!(cookie.partitioned());
+        assert_eq!(cookie.priority(), Some(CookiePriority::High));
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(Cookie::new("id", "1").validate().is_ok());
+        assert!(Cookie::new("id", "1")
+            .set_domain(".example.com")
+            .set_path("/app")
+            .set_same_site(SameSite::None)
+            .set_secure(true)
+            .validate()
+            .is_ok());
+
+        for (cookie, attribute) in [
+            (Cookie::new("id", "1").set_domain(""), "Domain="),
+            (
+                Cookie::new("id", "1").set_domain("exa mple.com"),
+                "Domain=exa mple.com",
+            ),
+            (
+                Cookie::new("id", "1").set_domain("example..com"),
+                "Domain=example..com",
+            ),
+            (
+                Cookie::new("id", "1").set_domain("-example.com"),
+                "Domain=-example.com",
+            ),
+            (Cookie::new("id", "1").set_path("app"), "Path=app"),
+            (Cookie::new("id", "1").set_path("/a;b"), "Path=/a;b"),
+            (
+                Cookie::new("id", "1").set_same_site(SameSite::None),
+                "SameSite=None",
+            ),
+            (Cookie::new("id", "1").set_partitioned(true), "Partitioned"),
+        ] {
+            assert!(matches!(
+                cookie.validate(),
+                Err(SerwerError::InvalidCookieAttribute(error_string)) if error_string == attribute
+            ));
+        }
+    }
+
+    #[test]
+    fn test_encoding() {
+        let cookie = Cookie::new("prefs", "a, b").set_encoding(CookieEncoding::Percent);
+
+        assert_eq!(cookie.encoding(), Some(CookieEncoding::Percent));
+        assert_eq!(cookie.value(), "a, b");
+        assert_eq!(cookie.encoded_value(), "a%2C%20b");
+        assert_eq!(Cookie::new("id", "1").encoded_value(), "1");
+    }
+
+    #[test]
+    fn test_decode() {
+        let cookie = Cookie::from_string("prefs=a%2C%20b").unwrap();
+
+        assert_eq!(
+            cookie.decode(CookieEncoding::Percent),
+            Some(Cookie::new("prefs", "a, b"))
+        );
+        assert_eq!(cookie.decode(CookieEncoding::Base64Url), None);
     }
 }
@@ -1,9 +1,11 @@
-use crate::{Cookie, SerwerError};
+#[cfg(any(feature = "signed", feature = "private"))]
+use crate::SecretKey;
+use crate::{Cookie, CookieEncoding, SerwerError};
 use std::collections::BTreeMap;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct Cookies {
-    cookies: BTreeMap<String, Cookie>,
+    cookies: BTreeMap<(String, Option<String>, Option<String>), Cookie>,
 }
 
 impl Cookies {
@@ -25,35 +27,110 @@ impl Cookies {
         for part in parts.iter() {
             let cookie = Cookie::from_string(part)?;
 
-            cookies.insert(cookie.name().clone(), cookie);
+            cookies.insert(Self::key(cookie.name(), &cookie), cookie);
         }
 
         Ok(Self { cookies })
     }
 
     pub fn cookie(&self, key: &str) -> Option<&Cookie> {
-        self.cookies.get(key)
+        self.cookies
+            .iter()
+            .find(|((name, _, _), _)| name == key)
+            .map(|(_, cookie)| cookie)
     }
 
     pub fn set_cookie(&mut self, key: &str, value: Cookie) {
-        self.cookies.insert(String::from(key), value);
+        self.cookies.insert(Self::key(key, &value), value);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cookies.is_empty()
+    }
+
+    pub(crate) fn decode(&self, encoding: CookieEncoding) -> Self {
+        Self {
+            cookies: self
+                .cookies
+                .iter()
+                .filter_map(|(key, cookie)| Some((key.clone(), cookie.decode(encoding)?)))
+                .collect(),
+        }
+    }
+
+    pub(crate) fn set_default_encoding(&mut self, encoding: CookieEncoding) {
+        for cookie in self.cookies.values_mut() {
+            if cookie.encoding().is_none() {
+                *cookie = cookie.clone().set_encoding(encoding);
+            }
+        }
+    }
+
+    #[cfg(any(feature = "signed", feature = "private"))]
+    pub(crate) fn extend(&mut self, cookies: Cookies) {
+        self.cookies.extend(cookies.cookies);
+    }
+
+    #[cfg(feature = "signed")]
+    pub(crate) fn sign(&self, secret_keys: &[SecretKey]) -> Result<Self, SerwerError> {
+        let mut cookies = BTreeMap::new();
+
+        for (key, cookie) in self.cookies.iter() {
+            let secret_key = secret_keys
+                .first()
+                .ok_or(SerwerError::MissingSecretKey(key.0.clone()))?;
+            let cookie = cookie
+                .with_value(secret_key.sign(&key.0, &cookie.encoded_value()))
+                .set_encoding(CookieEncoding::Raw);
+
+            cookies.insert(key.clone(), cookie);
+        }
+
+        Ok(Self { cookies })
+    }
+
+    #[cfg(feature = "private")]
+    pub(crate) fn encrypt(&self, secret_keys: &[SecretKey]) -> Result<Self, SerwerError> {
+        let mut cookies = BTreeMap::new();
+
+        for (key, cookie) in self.cookies.iter() {
+            let secret_key = secret_keys
+                .first()
+                .ok_or(SerwerError::MissingSecretKey(key.0.clone()))?;
+            let cookie = cookie
+                .with_value(secret_key.encrypt(&key.0, cookie.value()))
+                .set_encoding(CookieEncoding::Raw);
+
+            cookies.insert(key.clone(), cookie);
+        }
+
+        Ok(Self { cookies })
     }
 
     pub fn to_bytes(&self) -> Vec<u8> {
+        self.cookies_to_bytes(b"set-cookie")
+    }
+
+    pub fn to_bytes_preserving_case(&self) -> Vec<u8> {
+        self.cookies_to_bytes(b"Set-Cookie")
+    }
+
+    fn cookies_to_bytes(&self, header_name: &[u8]) -> Vec<u8> {
         let mut bytes: Vec<u8> = vec![];
 
-        for (name, cookie) in self.cookies.iter() {
-            bytes.extend(b"set-cookie: ");
+        for ((name, _, _), cookie) in self.cookies.iter() {
+            bytes.extend(header_name);
+            bytes.extend(b": ");
             bytes.extend(name.as_bytes());
             bytes.extend(b"=");
-            bytes.extend(cookie.value().as_bytes());
+            bytes.extend(cookie.encoded_value().as_bytes());
             if let Some(expires) = cookie.expires() {
                 bytes.extend(b"; Expires=");
-                bytes.extend(expires.as_bytes());
+                bytes.extend(expires.to_string().as_bytes());
             }
             if let Some(max_age) = cookie.max_age() {
                 bytes.extend(b"; Max-Age=");
-                bytes.extend(max_age.to_string().as_bytes());
+                bytes.extend(max_age.as_secs().to_string().as_bytes());
             }
             if let Some(domain) = cookie.domain() {
                 bytes.extend(b"; Domain=");
@@ -71,18 +148,35 @@ impl Cookies {
             }
             if let Some(same_site) = cookie.same_site() {
                 bytes.extend(b"; SameSite=");
-                bytes.extend(same_site.as_bytes());
+                bytes.extend(same_site.to_string().as_bytes());
+            }
+            if cookie.partitioned() {
+                bytes.extend(b"; Partitioned");
+            }
+            if let Some(priority) = cookie.priority() {
+                bytes.extend(b"; Priority=");
+                bytes.extend(priority.to_string().as_bytes());
             }
             bytes.extend(b"\r\n");
         }
 
         bytes
     }
+
+    fn key(name: &str, cookie: &Cookie) -> (String, Option<String>, Option<String>) {
+        (
+            String::from(name),
+            cookie.domain().cloned(),
+            cookie.path().cloned(),
+        )
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{CookiePriority, HttpDate, SameSite};
+    use std::time::Duration;
 
     #[test]
     fn test_from_string() {
@@ -142,7 +236,7 @@ mod tests {
         cookies.set_cookie(
             "id",
             Cookie::new("id", "1")
-                .set_expires("Mon, 18 Dec 2023 06:11:00 GMT")
+                .set_expires(HttpDate::from_unix_timestamp(1702879860))
                 .set_domain("localhost")
                 .set_path("/")
                 .set_secure(true),
@@ -150,11 +244,70 @@ mod tests {
         cookies.set_cookie(
             "name",
             Cookie::new("name", "John")
-                .set_max_age(86400)
+                .set_max_age(Duration::from_secs(86400))
                 .set_http_only(true)
-                .set_same_site("Strict"),
+                .set_same_site(SameSite::Strict),
+        );
+        cookies.set_cookie(
+            "theme",
+            Cookie::new("theme", "dark")
+                .set_secure(true)
+                .set_same_site(SameSite::None)
+                .set_partitioned(true)
+                .set_priority(CookiePriority::Low),
         );
         let result = cookies.to_bytes();
-        assert_eq!(String::from_utf8(result).unwrap(), String::from("set-cookie: id=1; Expires=Mon, 18 Dec 2023 06:11:00 GMT; Domain=localhost; Path=/; Secure\r\nset-cookie: name=John; Max-Age=86400; HttpOnly; SameSite=Strict\r\n"))
+        assert_eq!(String::from_utf8(result).unwrap(), String::from("set-cookie: id=1; Expires=Mon, 18 Dec 2023 06:11:00 GMT; Domain=localhost; Path=/; Secure\r\nset-cookie: name=John; Max-Age=86400; HttpOnly; SameSite=Strict\r\nset-cookie: theme=dark; Secure; SameSite=None; Partitioned; Priority=Low\r\n"))
+    }
+
+    #[test]
+    fn test_set_cookie_same_name() {
+        let mut cookies = Cookies::new();
+        cookies.set_cookie("id", Cookie::new("id", "1"));
+        cookies.set_cookie("id", Cookie::new("id", "2").set_path("/admin"));
+        cookies.set_cookie("id", Cookie::new("id", "3").set_domain("example.com"));
+        cookies.set_cookie("id", Cookie::new("id", "4").set_path("/admin"));
+
+        assert_eq!(cookies.cookie("id"), Some(&Cookie::new("id", "1")));
+        assert_eq!(
+            String::from_utf8(cookies.to_bytes()).unwrap(),
+            "set-cookie: id=1\r\nset-cookie: id=4; Path=/admin\r\nset-cookie: id=3; Domain=example.com\r\n"
+        );
+    }
+
+    #[test]
+    fn test_to_bytes_preserving_case() {
+        let mut cookies = Cookies::new();
+        cookies.set_cookie("id", Cookie::new("id", "1").set_path("/"));
+        let result = cookies.to_bytes_preserving_case();
+        assert_eq!(
+            String::from_utf8(result).unwrap(),
+            String::from("Set-Cookie: id=1; Path=/\r\n")
+        )
+    }
+
+    #[test]
+    fn test_decode() {
+        let cookies = Cookies::from_string("prefs=a%2C%20b; id=%FF").unwrap();
+        let result = cookies.decode(CookieEncoding::Percent);
+
+        assert_eq!(result.cookie("prefs"), Some(&Cookie::new("prefs", "a, b")));
+        assert_eq!(result.cookie("id"), None);
+    }
+
+    #[test]
+    fn test_set_default_encoding() {
+        let mut cookies = Cookies::new();
+        cookies.set_cookie("prefs", Cookie::new("prefs", "a, b"));
+        cookies.set_cookie(
+            "user",
+            Cookie::new("user", "Zoë").set_encoding(CookieEncoding::Base64Url),
+        );
+        cookies.set_default_encoding(CookieEncoding::Percent);
+
+        assert_eq!(
+            String::from_utf8(cookies.to_bytes()).unwrap(),
+            "set-cookie: prefs=a%2C%20b\r\nset-cookie: user=Wm_Dqw\r\n"
+        );
     }
 }
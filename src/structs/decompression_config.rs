@@ -0,0 +1,41 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecompressionConfig {
+    max_size: usize,
+}
+
+impl DecompressionConfig {
+    pub fn new() -> Self {
+        Self {
+            max_size: 10 * 1024 * 1024,
+        }
+    }
+
+    pub fn max_size(&self) -> usize {
+        self.max_size
+    }
+
+    pub fn set_max_size(mut self, max_size: usize) -> Self {
+        self.max_size = max_size;
+        self
+    }
+}
+
+impl Default for DecompressionConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decompression_config_builder() {
+        assert_eq!(DecompressionConfig::new().max_size(), 10 * 1024 * 1024);
+
+        let config = DecompressionConfig::new().set_max_size(1024);
+
+        assert_eq!(config.max_size(), 1024);
+    }
+}
@@ -0,0 +1,125 @@
+use crate::SerwerError;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntityTag {
+    weak: bool,
+    tag: String,
+}
+
+impl EntityTag {
+    pub fn strong(tag: &str) -> Self {
+        Self {
+            weak: false,
+            tag: String::from(tag),
+        }
+    }
+
+    pub fn weak(tag: &str) -> Self {
+        Self {
+            weak: true,
+            tag: String::from(tag),
+        }
+    }
+
+    pub fn from_string(string: &str) -> Result<Self, SerwerError> {
+        let (weak, quoted) = match string.strip_prefix("W/") {
+            Some(quoted) => (true, quoted),
+            None => (false, string),
+        };
+
+        if quoted.len() < 2 || !quoted.starts_with('"') || !quoted.ends_with('"') {
+            return Err(SerwerError::InvalidHeaderValue(String::from(string)));
+        }
+
+        let tag = &quoted[1..quoted.len() - 1];
+
+        if !tag.chars().all(|c| c == '!' || ('#'..='~').contains(&c)) {
+            return Err(SerwerError::InvalidHeaderValue(String::from(string)));
+        }
+
+        Ok(Self {
+            weak,
+            tag: String::from(tag),
+        })
+    }
+
+    pub fn is_weak(&self) -> bool {
+        self.weak
+    }
+
+    pub fn tag(&self) -> &String {
+        &self.tag
+    }
+
+    pub fn strong_eq(&self, other: &EntityTag) -> bool {
+        !self.weak && !other.weak && self.tag == other.tag
+    }
+
+    pub fn weak_eq(&self, other: &EntityTag) -> bool {
+        self.tag == other.tag
+    }
+}
+
+impl fmt::Display for EntityTag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.weak {
+            write!(f, "W/")?;
+        }
+
+        write!(f, "\"{}\"", self.tag)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_string() {
+        assert_eq!(
+            EntityTag::from_string("\"xyzzy\"").unwrap(),
+            EntityTag::strong("xyzzy")
+        );
+        assert_eq!(
+            EntityTag::from_string("W/\"xyzzy\"").unwrap(),
+            EntityTag::weak("xyzzy")
+        );
+        assert_eq!(
+            EntityTag::from_string("\"\"").unwrap(),
+            EntityTag::strong("")
+        );
+    }
+
+    #[test]
+    fn test_from_string_invalid() {
+        for string in [
+            "xyzzy",
+            "\"xyzzy",
+            "w/\"xyzzy\"",
+            "\"xy\"zy\"",
+            "\"xy zy\"",
+            "",
+        ] {
+            assert!(matches!(
+                EntityTag::from_string(string),
+                Err(SerwerError::InvalidHeaderValue(error_string)) if error_string == string
+            ));
+        }
+    }
+
+    #[test]
+    fn test_to_string() {
+        assert_eq!(EntityTag::strong("xyzzy").to_string(), "\"xyzzy\"");
+        assert_eq!(EntityTag::weak("xyzzy").to_string(), "W/\"xyzzy\"");
+    }
+
+    #[test]
+    fn test_comparison() {
+        assert!(EntityTag::strong("1").strong_eq(&EntityTag::strong("1")));
+        assert!(!EntityTag::strong("1").strong_eq(&EntityTag::weak("1")));
+        assert!(!EntityTag::weak("1").strong_eq(&EntityTag::weak("1")));
+        assert!(EntityTag::weak("1").weak_eq(&EntityTag::strong("1")));
+        assert!(!EntityTag::weak("1").weak_eq(&EntityTag::weak("2")));
+    }
+}
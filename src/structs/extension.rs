@@ -0,0 +1,41 @@
+use crate::{FromRequest, Request, SerwerError};
+use std::any;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Extension<T>(pub T);
+
+impl<T: Clone + Send + Sync + 'static> FromRequest for Extension<T> {
+    fn from_request(request: &Request) -> Result<Self, SerwerError> {
+        request
+            .extensions()
+            .get::<T>()
+            .cloned()
+            .map(Self)
+            .ok_or(SerwerError::MissingExtension(String::from(
+                any::type_name::<T>(),
+            )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request_from_bytes;
+
+    #[test]
+    fn test_from_request() {
+        let mut request = request_from_bytes(b"GET / HTTP/1.1\r\n\r\n").unwrap();
+
+        assert!(matches!(
+            Extension::<u32>::from_request(&request),
+            Err(SerwerError::MissingExtension(error_string)) if &error_string == "u32"
+        ));
+
+        request.extensions_mut().insert(42u32);
+
+        assert_eq!(
+            Extension::<u32>::from_request(&request).unwrap(),
+            Extension(42)
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use std::{
+    any::{self, Any, TypeId},
+    collections::HashMap,
+    fmt,
+};
+
+trait AnyExtension: Any + Send + Sync {
+    fn clone_box(&self) -> Box<dyn AnyExtension>;
+
+    fn as_any(&self) -> &dyn Any;
+
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any>;
+}
+
+impl<T: Clone + Send + Sync + 'static> AnyExtension for T {
+    fn clone_box(&self) -> Box<dyn AnyExtension> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+#[derive(Default)]
+pub struct Extensions {
+    extensions: HashMap<TypeId, (&'static str, Box<dyn AnyExtension>)>,
+}
+
+impl Extensions {
+    pub fn new() -> Self {
+        Self {
+            extensions: HashMap::new(),
+        }
+    }
+
+    pub fn insert<T: Clone + Send + Sync + 'static>(&mut self, value: T) -> Option<T> {
+        self.extensions
+            .insert(TypeId::of::<T>(), (any::type_name::<T>(), Box::new(value)))
+            .and_then(|(_, extension)| extension.into_any().downcast().ok())
+            .map(|extension| *extension)
+    }
+
+    pub fn get<T: 'static>(&self) -> Option<&T> {
+        self.extensions
+            .get(&TypeId::of::<T>())
+            .and_then(|(_, extension)| (**extension).as_any().downcast_ref())
+    }
+
+    pub fn get_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        self.extensions
+            .get_mut(&TypeId::of::<T>())
+            .and_then(|(_, extension)| (**extension).as_any_mut().downcast_mut())
+    }
+
+    pub fn remove<T: 'static>(&mut self) -> Option<T> {
+        self.extensions
+            .remove(&TypeId::of::<T>())
+            .and_then(|(_, extension)| extension.into_any().downcast().ok())
+            .map(|extension| *extension)
+    }
+
+    pub fn contains<T: 'static>(&self) -> bool {
+        self.extensions.contains_key(&TypeId::of::<T>())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.extensions.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.extensions.clear();
+    }
+}
+
+impl Clone for Extensions {
+    fn clone(&self) -> Self {
+        Self {
+            extensions: self
+                .extensions
+                .iter()
+                .map(|(type_id, (name, extension))| (*type_id, (*name, (**extension).clone_box())))
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Debug for Extensions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut names: Vec<&str> = self.extensions.values().map(|(name, _)| *name).collect();
+        names.sort();
+
+        f.debug_set().entries(names).finish()
+    }
+}
+
+impl PartialEq for Extensions {
+    fn eq(&self, other: &Self) -> bool {
+        self.extensions.len() == other.extensions.len()
+            && self
+                .extensions
+                .keys()
+                .all(|type_id| other.extensions.contains_key(type_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct User {
+        id: u32,
+    }
+
+    #[test]
+    fn test_insert_and_get() {
+        let mut extensions = Extensions::new();
+        assert!(extensions.is_empty());
+        assert_eq!(extensions.get::<User>(), None);
+
+        assert_eq!(extensions.insert(User { id: 1 }), None);
+        assert_eq!(extensions.insert(String::from("request-id")), None);
+        assert_eq!(extensions.insert(User { id: 2 }), Some(User { id: 1 }));
+
+        assert_eq!(extensions.get::<User>(), Some(&User { id: 2 }));
+        assert_eq!(
+            extensions.get::<String>(),
+            Some(&String::from("request-id"))
+        );
+        assert!(extensions.contains::<User>());
+        assert!(!extensions.contains::<u32>());
+    }
+
+    #[test]
+    fn test_get_mut_and_remove() {
+        let mut extensions = Extensions::new();
+        extensions.insert(User { id: 1 });
+
+        extensions.get_mut::<User>().unwrap().id = 2;
+        assert_eq!(extensions.get::<User>(), Some(&User { id: 2 }));
+
+        assert_eq!(extensions.remove::<User>(), Some(User { id: 2 }));
+        assert_eq!(extensions.remove::<User>(), None);
+        assert!(extensions.is_empty());
+
+        extensions.insert(1u32);
+        extensions.clear();
+        assert!(extensions.is_empty());
+    }
+
+    #[test]
+    fn test_clone() {
+        let mut extensions = Extensions::new();
+        extensions.insert(User { id: 1 });
+
+        let mut extensions_clone = extensions.clone();
+        extensions_clone.get_mut::<User>().unwrap().id = 2;
+
+        assert_eq!(extensions.get::<User>(), Some(&User { id: 1 }));
+        assert_eq!(extensions_clone.get::<User>(), Some(&User { id: 2 }));
+        assert_eq!(extensions, extensions_clone);
+        assert_ne!(extensions, Extensions::new());
+    }
+
+    #[test]
+    fn test_debug() {
+        let mut extensions = Extensions::new();
+        extensions.insert(1u32);
+        extensions.insert(true);
+
+        assert_eq!(format!("{:?}", extensions), r#"{"bool", "u32"}"#);
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{decode_query, SerwerError};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Form {
+    fields: Vec<(String, String)>,
+}
+
+impl Form {
+    pub fn new() -> Self {
+        Self { fields: vec![] }
+    }
+
+    pub fn from_string(string: &str) -> Result<Self, SerwerError> {
+        let mut fields = vec![];
+
+        for part in string.split('&') {
+            if part.is_empty() {
+                continue;
+            }
+
+            let (name, value) = part.split_once('=').unwrap_or((part, ""));
+
+            fields.push((decode_query(name)?, decode_query(value)?));
+        }
+
+        Ok(Self { fields })
+    }
+
+    pub fn field(&self, key: &str) -> Option<&String> {
+        self.fields
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value)
+    }
+
+    pub fn fields_all(&self, key: &str) -> Vec<&String> {
+        self.fields
+            .iter()
+            .filter(|(name, _)| name == key)
+            .map(|(_, value)| value)
+            .collect()
+    }
+
+    pub fn fields(&self) -> &Vec<(String, String)> {
+        &self.fields
+    }
+
+    pub fn set_field(&mut self, key: &str, value: &str) {
+        self.fields.push((String::from(key), String::from(value)));
+    }
+
+    pub fn to_map(&self) -> HashMap<String, String> {
+        let mut map = HashMap::new();
+
+        for (name, value) in self.fields.iter() {
+            map.entry(name.clone()).or_insert(value.clone());
+        }
+
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_string() {
+        let result = Form::from_string("name=John+Doe&city=New%20York").unwrap();
+        assert_eq!(result.field("name"), Some(&String::from("John Doe")));
+        assert_eq!(result.field("city"), Some(&String::from("New York")));
+        assert_eq!(result.field("age"), None);
+    }
+
+    #[test]
+    fn test_from_string_repeated_keys() {
+        let result = Form::from_string("tag=a&tag=b&id=1&tag=c").unwrap();
+        assert_eq!(result.field("tag"), Some(&String::from("a")));
+        assert_eq!(
+            result.fields_all("tag"),
+            vec![&String::from("a"), &String::from("b"), &String::from("c")]
+        );
+        assert_eq!(result.to_map().get("tag"), Some(&String::from("a")));
+        assert_eq!(result.fields().len(), 4);
+    }
+
+    #[test]
+    fn test_from_string_empty_values() {
+        let result = Form::from_string("name=&flag&&comment=").unwrap();
+        assert_eq!(result.field("name"), Some(&String::from("")));
+        assert_eq!(result.field("flag"), Some(&String::from("")));
+        assert_eq!(result.field("comment"), Some(&String::from("")));
+        assert_eq!(result.fields().len(), 3);
+
+        let result = Form::from_string("").unwrap();
+        assert_eq!(result, Form::new());
+    }
+
+    #[test]
+    fn test_from_string_invalid() {
+        let result = Form::from_string("name=Jo%hn");
+        assert!(matches!(
+            result,
+            Err(SerwerError::DecodeError(error_string)) if &error_string == "Jo%hn"
+        ));
+
+        let result = Form::from_string("name=John%2");
+        assert!(matches!(
+            result,
+            Err(SerwerError::DecodeError(error_string)) if &error_string == "John%2"
+        ));
+    }
+
+    #[test]
+    fn test_set_field() {
+        let mut form = Form::new();
+        form.set_field("id", "1");
+        form.set_field("id", "2");
+        assert_eq!(
+            form.fields_all("id"),
+            vec![&String::from("1"), &String::from("2")]
+        );
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{FromRequest, Request, SerwerError, TypedHeader};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Header<T>(pub T);
+
+impl<T: TypedHeader> FromRequest for Header<T> {
+    fn from_request(request: &Request) -> Result<Self, SerwerError> {
+        let value = request
+            .header_joined(T::name())
+            .ok_or(SerwerError::MissingHeader(String::from(T::name())))?;
+
+        Ok(Self(T::from_value(&value)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{request_from_bytes, ContentType, MediaType};
+
+    #[test]
+    fn test_from_request() {
+        let request =
+            request_from_bytes(b"GET / HTTP/1.1\r\nContent-Type: text/html\r\n\r\n").unwrap();
+
+        assert_eq!(
+            Header::<ContentType>::from_request(&request).unwrap(),
+            Header(ContentType(MediaType::new("text", "html")))
+        );
+
+        let request = request_from_bytes(b"GET / HTTP/1.1\r\nContent-Type: text\r\n\r\n").unwrap();
+
+        assert!(matches!(
+            Header::<ContentType>::from_request(&request),
+            Err(SerwerError::InvalidHeaderValue(error_string)) if &error_string == "text"
+        ));
+
+        let request = request_from_bytes(b"GET / HTTP/1.1\r\n\r\n").unwrap();
+
+        assert!(matches!(
+            Header::<ContentType>::from_request(&request),
+            Err(SerwerError::MissingHeader(error_string)) if &error_string == "Content-Type"
+        ));
+    }
+}
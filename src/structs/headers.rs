@@ -1,5 +1,4 @@
 use crate::SerwerError;
-use std::collections::BTreeMap;
 
 const NAME_ALLOWED_CHARACTERS: &str =
     "!#$%&'*+-.0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ^_`abcdefghijklmnopqrstuvwxyz|~";
@@ -8,14 +7,12 @@ const VALUE_ALLOWED_CHARACTERS: &str = " !\"#$%&'()*+,-./0123456789:;<=>?@ABCDEF
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Headers {
-    headers: BTreeMap<String, String>,
+    headers: Vec<(String, String)>,
 }
 
 impl Headers {
     pub fn new() -> Self {
-        Self {
-            headers: BTreeMap::new(),
-        }
+        Self { headers: vec![] }
     }
 
     pub fn set_header_from_string(&mut self, string: &str) -> Result<(), SerwerError> {
@@ -36,25 +33,94 @@ impl Headers {
             return Err(SerwerError::InvalidHeaderCharacters(String::from(string)));
         }
 
-        self.headers
-            .insert(name.to_lowercase(), String::from(value));
+        self.append(name, value);
 
         Ok(())
     }
 
     pub fn set_header(&mut self, name: &str, value: &str) {
+        let mut replaced = false;
+
+        self.headers.retain_mut(|(header_name, header_value)| {
+            if !header_name.eq_ignore_ascii_case(name) {
+                return true;
+            }
+
+            if replaced {
+                return false;
+            }
+
+            *header_name = String::from(name);
+            *header_value = String::from(value);
+            replaced = true;
+
+            true
+        });
+
+        if !replaced {
+            self.append(name, value);
+        }
+    }
+
+    pub fn append(&mut self, name: &str, value: &str) {
+        self.headers.push((String::from(name), String::from(value)));
+    }
+
+    pub fn remove_header(&mut self, name: &str) {
         self.headers
-            .insert(name.to_lowercase(), String::from(value));
+            .retain(|(header_name, _)| !header_name.eq_ignore_ascii_case(name));
     }
 
     pub fn header(&self, name: &str) -> Option<&String> {
-        self.headers.get(name.to_lowercase().as_str())
+        self.header_all(name).into_iter().next()
+    }
+
+    pub fn header_all(&self, name: &str) -> Vec<&String> {
+        self.headers
+            .iter()
+            .filter(|(header_name, _)| header_name.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value)
+            .collect()
+    }
+
+    pub fn header_joined(&self, name: &str) -> Option<String> {
+        let values = self.header_all(name);
+
+        if values.is_empty() {
+            return None;
+        }
+
+        Some(
+            values
+                .into_iter()
+                .map(String::as_str)
+                .collect::<Vec<&str>>()
+                .join(", "),
+        )
     }
 
     pub fn to_bytes(&self) -> Vec<u8> {
+        let mut headers: Vec<(String, &String)> = self
+            .headers
+            .iter()
+            .map(|(name, value)| (name.to_lowercase(), value))
+            .collect();
+        headers.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        Self::headers_to_bytes(headers.iter().map(|(name, value)| (name, *value)))
+    }
+
+    pub fn to_bytes_preserving_case(&self) -> Vec<u8> {
+        Self::headers_to_bytes(self.headers.iter().map(|(name, value)| (name, value)))
+    }
+
+    fn headers_to_bytes<'a, I>(headers: I) -> Vec<u8>
+    where
+        I: Iterator<Item = (&'a String, &'a String)>,
+    {
         let mut bytes: Vec<u8> = vec![];
 
-        for (name, value) in self.headers.iter() {
+        for (name, value) in headers {
             bytes.extend(name.as_bytes());
             bytes.extend(b": ");
             bytes.extend(value.as_bytes());
@@ -141,6 +207,50 @@ mod tests {
         assert_eq!(headers.header(""), None);
     }
 
+    #[test]
+    fn test_set_header_from_string_repeated() {
+        let mut headers = Headers::new();
+        headers.set_header_from_string("Accept: text/html").unwrap();
+        headers
+            .set_header_from_string("X-Forwarded-For: 10.0.0.1")
+            .unwrap();
+        headers
+            .set_header_from_string("accept: application/json")
+            .unwrap();
+
+        assert_eq!(headers.header("Accept"), Some(&String::from("text/html")));
+        assert_eq!(
+            headers.header_all("Accept"),
+            vec![
+                &String::from("text/html"),
+                &String::from("application/json")
+            ]
+        );
+        assert_eq!(
+            headers.header_joined("Accept"),
+            Some(String::from("text/html, application/json"))
+        );
+        assert_eq!(headers.header_all("Host"), Vec::<&String>::new());
+        assert_eq!(headers.header_joined("Host"), None);
+    }
+
+    #[test]
+    fn test_set_header_replaces_values() {
+        let mut headers = Headers::new();
+        headers.append("Vary", "Accept");
+        headers.append("Vary", "Cookie");
+        headers.set_header("vary", "Accept-Encoding");
+
+        assert_eq!(
+            headers.header_all("Vary"),
+            vec![&String::from("Accept-Encoding")]
+        );
+
+        headers.remove_header("VARY");
+
+        assert_eq!(headers.header("Vary"), None);
+    }
+
     #[test]
     fn test_to_bytes() {
         let mut headers = Headers::new();
@@ -152,4 +262,38 @@ mod tests {
             String::from("connection: keep-alive\r\nhost: localhost:80\r\n")
         )
     }
+
+    #[test]
+    fn test_to_bytes_multiple_values() {
+        let mut headers = Headers::new();
+        headers.append("Link", "</style.css>; rel=preload");
+        headers.set_header("Content-Length", "0");
+        headers.append("Link", "</app.js>; rel=preload");
+        let result = headers.to_bytes();
+        assert_eq!(
+            String::from_utf8(result).unwrap(),
+            String::from("content-length: 0\r\nlink: </style.css>; rel=preload\r\nlink: </app.js>; rel=preload\r\n")
+        )
+    }
+
+    #[test]
+    fn test_to_bytes_preserving_case() {
+        let mut headers = Headers::new();
+        headers.set_header("Host", "localhost:80");
+        headers.set_header("X-Request-ID", "1");
+        headers.append("Link", "</style.css>; rel=preload");
+        headers.set_header("Connection", "keep-alive");
+        headers.append("link", "</app.js>; rel=preload");
+        headers.set_header("x-request-id", "2");
+
+        assert_eq!(headers.header("X-REQUEST-ID"), Some(&String::from("2")));
+        assert_eq!(
+            String::from_utf8(headers.to_bytes_preserving_case()).unwrap(),
+            String::from("Host: localhost:80\r\nx-request-id: 2\r\nLink: </style.css>; rel=preload\r\nConnection: keep-alive\r\nlink: </app.js>; rel=preload\r\n")
+        );
+        assert_eq!(
+            String::from_utf8(headers.to_bytes()).unwrap(),
+            String::from("connection: keep-alive\r\nhost: localhost:80\r\nlink: </style.css>; rel=preload\r\nlink: </app.js>; rel=preload\r\nx-request-id: 2\r\n")
+        );
+    }
 }
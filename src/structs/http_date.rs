@@ -0,0 +1,268 @@
+use crate::SerwerError;
+use std::{
+    fmt,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+const DAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+const LONG_DAYS: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+const MAX_TIMESTAMP: u64 = 253402300799;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HttpDate {
+    timestamp: u64,
+}
+
+impl HttpDate {
+    pub fn now() -> Self {
+        Self::from_system_time(SystemTime::now())
+    }
+
+    pub fn from_unix_timestamp(timestamp: u64) -> Self {
+        Self { timestamp }
+    }
+
+    pub fn from_system_time(time: SystemTime) -> Self {
+        Self {
+            timestamp: time
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+        }
+    }
+
+    pub fn from_string(string: &str) -> Result<Self, SerwerError> {
+        Self::parse_imf_fixdate(string)
+            .or_else(|| Self::parse_rfc850(string))
+            .or_else(|| Self::parse_asctime(string))
+            .ok_or(SerwerError::InvalidDate(String::from(string)))
+    }
+
+    pub fn unix_timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    pub fn to_system_time(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(self.timestamp)
+    }
+
+    pub fn add(&self, duration: Duration) -> Self {
+        Self {
+            timestamp: self
+                .timestamp
+                .saturating_add(duration.as_secs())
+                .min(MAX_TIMESTAMP),
+        }
+    }
+
+    fn parse_imf_fixdate(string: &str) -> Option<Self> {
+        let (day_name, rest) = string.split_once(", ")?;
+        let parts: Vec<&str> = rest.split(' ').collect();
+
+        if !DAYS.contains(&day_name) || parts.len() != 5 || parts[4] != "GMT" {
+            return None;
+        }
+
+        if parts[0].len() != 2 || parts[2].len() != 4 {
+            return None;
+        }
+
+        Self::from_parts(parts[2].parse().ok()?, parts[1], parts[0], parts[3])
+    }
+
+    fn parse_rfc850(string: &str) -> Option<Self> {
+        let (day_name, rest) = string.split_once(", ")?;
+        let parts: Vec<&str> = rest.split(' ').collect();
+
+        if !LONG_DAYS.contains(&day_name) || parts.len() != 3 || parts[2] != "GMT" {
+            return None;
+        }
+
+        let date: Vec<&str> = parts[0].split('-').collect();
+
+        if date.len() != 3 || date[0].len() != 2 || date[2].len() != 2 {
+            return None;
+        }
+
+        let year: u64 = date[2].parse().ok()?;
+        let year = if year < 70 { 2000 + year } else { 1900 + year };
+
+        Self::from_parts(year, date[1], date[0], parts[1])
+    }
+
+    fn parse_asctime(string: &str) -> Option<Self> {
+        let parts: Vec<&str> = string.split_whitespace().collect();
+
+        if parts.len() != 5 || !DAYS.contains(&parts[0]) || parts[4].len() != 4 {
+            return None;
+        }
+
+        Self::from_parts(parts[4].parse().ok()?, parts[1], parts[2], parts[3])
+    }
+
+    fn from_parts(year: u64, month: &str, day: &str, time: &str) -> Option<Self> {
+        let month = MONTHS.iter().position(|m| *m == month)? as u64 + 1;
+        let day: u64 = day.parse().ok()?;
+        let time: Vec<&str> = time.split(':').collect();
+
+        if time.len() != 3 || time.iter().any(|part| part.len() != 2) {
+            return None;
+        }
+
+        let hours: u64 = time[0].parse().ok()?;
+        let minutes: u64 = time[1].parse().ok()?;
+        let seconds: u64 = time[2].parse().ok()?;
+
+        if year < 1970 || day == 0 || day > 31 || hours > 23 || minutes > 59 || seconds > 60 {
+            return None;
+        }
+
+        let days = days_from_civil(year, month, day);
+
+        Some(Self {
+            timestamp: days * 86400 + hours * 3600 + minutes * 60 + seconds,
+        })
+    }
+}
+
+impl fmt::Display for HttpDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let days = self.timestamp / 86400;
+        let seconds = self.timestamp % 86400;
+        let (year, month, day) = civil_from_days(days);
+
+        write!(
+            f,
+            "{}, {:02} {} {} {:02}:{:02}:{:02} GMT",
+            DAYS[((days + 3) % 7) as usize],
+            day,
+            MONTHS[(month - 1) as usize],
+            year,
+            seconds / 3600,
+            seconds % 3600 / 60,
+            seconds % 60
+        )
+    }
+}
+
+fn days_from_civil(year: u64, month: u64, day: u64) -> u64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year / 400;
+    let year_of_era = year - era * 400;
+    let month = (month + 9) % 12;
+    let day_of_year = (153 * month + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146097 + day_of_era - 719468
+}
+
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let days = days + 719468;
+    let era = days / 146097;
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let month = if month < 10 { month + 3 } else { month - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_string() {
+        let expected = HttpDate::from_unix_timestamp(784111777);
+
+        assert_eq!(
+            HttpDate::from_string("Sun, 06 Nov 1994 08:49:37 GMT").unwrap(),
+            expected
+        );
+        assert_eq!(
+            HttpDate::from_string("Sunday, 06-Nov-94 08:49:37 GMT").unwrap(),
+            expected
+        );
+        assert_eq!(
+            HttpDate::from_string("Sun Nov  6 08:49:37 1994").unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_from_string_invalid() {
+        for string in [
+            "",
+            "Sun, 06 Nov 1994 08:49:37 UTC",
+            "Sun, 6 Nov 1994 08:49:37 GMT",
+            "Sun, 06 Foo 1994 08:49:37 GMT",
+            "Sun, 06 Nov 1994 25:49:37 GMT",
+            "Mon, 18 Dec 2023 06:11 GMT",
+        ] {
+            assert!(matches!(
+                HttpDate::from_string(string),
+                Err(SerwerError::InvalidDate(error_string)) if error_string == string
+            ));
+        }
+    }
+
+    #[test]
+    fn test_to_string() {
+        assert_eq!(
+            HttpDate::from_unix_timestamp(784111777).to_string(),
+            "Sun, 06 Nov 1994 08:49:37 GMT"
+        );
+        assert_eq!(
+            HttpDate::from_unix_timestamp(0).to_string(),
+            "Thu, 01 Jan 1970 00:00:00 GMT"
+        );
+        assert_eq!(
+            HttpDate::from_unix_timestamp(951782400).to_string(),
+            "Tue, 29 Feb 2000 00:00:00 GMT"
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for timestamp in [0, 68169600, 1702879860, 4102444799] {
+            let date = HttpDate::from_unix_timestamp(timestamp);
+            assert_eq!(HttpDate::from_string(&date.to_string()).unwrap(), date);
+        }
+    }
+
+    #[test]
+    fn test_system_time() {
+        let date = HttpDate::from_unix_timestamp(1702879860);
+        assert_eq!(HttpDate::from_system_time(date.to_system_time()), date);
+        assert_eq!(
+            date.add(Duration::from_secs(60)).unix_timestamp(),
+            1702879920
+        );
+    }
+
+    #[test]
+    fn test_add_overflow() {
+        let date = HttpDate::from_unix_timestamp(1702879860).add(Duration::MAX);
+
+        assert_eq!(date.unix_timestamp(), 253402300799);
+        assert_eq!(date.to_string(), "Fri, 31 Dec 9999 23:59:59 GMT");
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{FromRequest, IntoResponse, Request, Response, SerwerError};
+use serde::{de::DeserializeOwned, Serialize};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Json<T>(pub T);
+
+impl<T: DeserializeOwned> FromRequest for Json<T> {
+    fn from_request(request: &Request) -> Result<Self, SerwerError> {
+        Ok(Self(request.json()?))
+    }
+}
+
+impl<T: Serialize> IntoResponse for Json<T> {
+    fn into_response(self, mut response: Response) -> Response {
+        response.json(&self.0);
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{request_from_bytes, StatusCode, Version};
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Task {
+        id: u32,
+    }
+
+    #[test]
+    fn test_from_request() {
+        let request = request_from_bytes(
+            b"POST / HTTP/1.1\r\nContent-Type: application/json\r\nContent-Length: 8\r\n\r\n{\"id\":1}",
+        )
+        .unwrap();
+
+        assert_eq!(
+            Json::<Task>::from_request(&request).unwrap(),
+            Json(Task { id: 1 })
+        );
+
+        let request = request_from_bytes(
+            b"POST / HTTP/1.1\r\nContent-Type: application/json\r\nContent-Length: 2\r\n\r\n{}",
+        )
+        .unwrap();
+
+        assert_eq!(
+            Json::<Task>::from_request(&request)
+                .unwrap_err()
+                .status_code(),
+            StatusCode::UnprocessableEntity
+        );
+    }
+
+    #[test]
+    fn test_into_response() {
+        let response = Json(Task { id: 1 }).into_response(Response::new(&Version::HTTP_1_1));
+
+        assert_eq!(
+            String::from_utf8(response.write()).unwrap(),
+            "HTTP/1.1 200 OK\r\ncontent-length: 8\r\ncontent-type: application/json\r\n\r\n{\"id\":1}"
+        );
+    }
+}
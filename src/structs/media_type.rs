@@ -0,0 +1,228 @@
+use crate::{is_token, split_quoted, unquote, SerwerError};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MediaType {
+    main_type: String,
+    sub_type: String,
+    params: Vec<(String, String)>,
+}
+
+impl MediaType {
+    pub fn new(main_type: &str, sub_type: &str) -> Self {
+        Self {
+            main_type: main_type.to_lowercase(),
+            sub_type: sub_type.to_lowercase(),
+            params: vec![],
+        }
+    }
+
+    pub fn from_string(string: &str) -> Result<Self, SerwerError> {
+        let parts = split_quoted(string, ';');
+        let (main_type, sub_type) = parts[0]
+            .split_once('/')
+            .ok_or(SerwerError::InvalidHeaderValue(String::from(string)))?;
+
+        if !is_token(main_type) || !is_token(sub_type) {
+            return Err(SerwerError::InvalidHeaderValue(String::from(string)));
+        }
+
+        let mut media_type = Self::new(main_type, sub_type);
+
+        for part in parts[1..].iter() {
+            let (name, value) = part
+                .split_once('=')
+                .ok_or(SerwerError::InvalidHeaderValue(String::from(string)))?;
+
+            if !is_token(name) {
+                return Err(SerwerError::InvalidHeaderValue(String::from(string)));
+            }
+
+            media_type = media_type.set_param(name, &unquote(value));
+        }
+
+        Ok(media_type)
+    }
+
+    pub fn from_extension(extension: &str) -> Self {
+        let (main_type, sub_type) = match extension.to_lowercase().as_str() {
+            "html" | "htm" => ("text", "html"),
+            "css" => ("text", "css"),
+            "js" | "mjs" => ("text", "javascript"),
+            "txt" => ("text", "plain"),
+            "csv" => ("text", "csv"),
+            "md" => ("text", "markdown"),
+            "json" => ("application", "json"),
+            "xml" => ("application", "xml"),
+            "pdf" => ("application", "pdf"),
+            "wasm" => ("application", "wasm"),
+            "zip" => ("application", "zip"),
+            "gz" => ("application", "gzip"),
+            "svg" => ("image", "svg+xml"),
+            "png" => ("image", "png"),
+            "jpg" | "jpeg" => ("image", "jpeg"),
+            "gif" => ("image", "gif"),
+            "webp" => ("image", "webp"),
+            "avif" => ("image", "avif"),
+            "ico" => ("image", "x-icon"),
+            "woff" => ("font", "woff"),
+            "woff2" => ("font", "woff2"),
+            "ttf" => ("font", "ttf"),
+            "otf" => ("font", "otf"),
+            "mp3" => ("audio", "mpeg"),
+            "ogg" => ("audio", "ogg"),
+            "wav" => ("audio", "wav"),
+            "mp4" => ("video", "mp4"),
+            "webm" => ("video", "webm"),
+            _ => ("application", "octet-stream"),
+        };
+
+        let media_type = Self::new(main_type, sub_type);
+
+        if main_type == "text" {
+            media_type.set_param("charset", "utf-8")
+        } else {
+            media_type
+        }
+    }
+
+    pub fn main_type(&self) -> &String {
+        &self.main_type
+    }
+
+    pub fn sub_type(&self) -> &String {
+        &self.sub_type
+    }
+
+    pub fn essence(&self) -> String {
+        format!("{}/{}", self.main_type, self.sub_type)
+    }
+
+    pub fn param(&self, name: &str) -> Option<&String> {
+        let name = name.to_lowercase();
+
+        self.params
+            .iter()
+            .find(|(param_name, _)| param_name == &name)
+            .map(|(_, value)| value)
+    }
+
+    pub fn params(&self) -> &Vec<(String, String)> {
+        &self.params
+    }
+
+    pub fn set_param(mut self, name: &str, value: &str) -> Self {
+        self.params.push((name.to_lowercase(), String::from(value)));
+        self
+    }
+
+    pub fn matches(&self, other: &MediaType) -> bool {
+        let main_type_matches =
+            self.main_type == "*" || other.main_type == "*" || self.main_type == other.main_type;
+        let sub_type_matches =
+            self.sub_type == "*" || other.sub_type == "*" || self.sub_type == other.sub_type;
+
+        main_type_matches && sub_type_matches
+    }
+}
+
+impl fmt::Display for MediaType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.main_type, self.sub_type)?;
+
+        for (name, value) in self.params.iter() {
+            if is_token(value) {
+                write!(f, "; {}={}", name, value)?;
+            } else {
+                write!(f, "; {}=\"{}\"", name, value.replace('"', "\\\""))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_extension() {
+        assert_eq!(
+            MediaType::from_extension("html").to_string(),
+            "text/html; charset=utf-8"
+        );
+        assert_eq!(
+            MediaType::from_extension("PNG"),
+            MediaType::new("image", "png")
+        );
+        assert_eq!(
+            MediaType::from_extension("svg"),
+            MediaType::new("image", "svg+xml")
+        );
+        assert_eq!(
+            MediaType::from_extension("exe"),
+            MediaType::new("application", "octet-stream")
+        );
+    }
+
+    #[test]
+    fn test_from_string() {
+        let result = MediaType::from_string("text/html").unwrap();
+        assert_eq!(result, MediaType::new("text", "html"));
+        assert_eq!(result.essence(), "text/html");
+
+        let result = MediaType::from_string("Text/HTML; Charset=UTF-8").unwrap();
+        assert_eq!(result.main_type(), "text");
+        assert_eq!(result.sub_type(), "html");
+        assert_eq!(result.param("charset"), Some(&String::from("UTF-8")));
+
+        let result = MediaType::from_string("multipart/form-data; boundary=\"a; b\"").unwrap();
+        assert_eq!(result.param("boundary"), Some(&String::from("a; b")));
+    }
+
+    #[test]
+    fn test_from_string_invalid() {
+        for string in [
+            "",
+            "text",
+            "text/",
+            "/html",
+            "te xt/html",
+            "text/html; charset",
+        ] {
+            assert!(matches!(
+                MediaType::from_string(string),
+                Err(SerwerError::InvalidHeaderValue(error_string)) if error_string == string
+            ));
+        }
+    }
+
+    #[test]
+    fn test_to_string() {
+        let media_type = MediaType::new("text", "plain").set_param("charset", "utf-8");
+        assert_eq!(media_type.to_string(), "text/plain; charset=utf-8");
+
+        let media_type = MediaType::new("multipart", "form-data").set_param("boundary", "a b");
+        assert_eq!(
+            media_type.to_string(),
+            "multipart/form-data; boundary=\"a b\""
+        );
+        assert_eq!(
+            MediaType::from_string(&media_type.to_string()).unwrap(),
+            media_type
+        );
+    }
+
+    #[test]
+    fn test_matches() {
+        let html = MediaType::new("text", "html");
+
+        assert!(html.matches(&MediaType::new("text", "html")));
+        assert!(html.matches(&MediaType::new("text", "*")));
+        assert!(html.matches(&MediaType::new("*", "*")));
+        assert!(MediaType::new("*", "*").matches(&html));
+        assert!(!html.matches(&MediaType::new("text", "plain")));
+        assert!(!html.matches(&MediaType::new("application", "*")));
+    }
+}
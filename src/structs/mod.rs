@@ -1,31 +1,99 @@
 mod action;
+mod cache_control;
+mod cache_entry;
+mod compression_config;
+mod config;
 mod cookie;
 mod cookies;
 mod data;
+mod decompression_config;
+mod entity_tag;
+mod extension;
+mod extensions;
+mod form;
+mod header;
 mod headers;
+mod http_date;
+#[cfg(feature = "serde")]
+mod json;
+mod media_type;
+mod multipart;
+mod multipart_config;
+mod normalization_config;
+#[cfg(feature = "serde")]
+mod pairs_deserializer;
 mod params;
+mod part;
 mod path;
+#[cfg(feature = "serde")]
+mod path_params;
+mod quality_item;
+#[cfg(feature = "serde")]
+mod query;
 mod query_params;
 mod request;
 mod response;
+mod response_cache;
+mod rewrite_rule;
 mod route;
+#[cfg(any(feature = "signed", feature = "private"))]
+mod secret_key;
 mod segment;
+#[cfg(feature = "serde")]
+mod serde_form;
 mod serwer;
+mod states;
+mod static_files;
 mod thread_pool;
+mod typed_headers;
 mod worker;
 
 pub use action::*;
+pub use cache_control::*;
+pub use cache_entry::*;
+pub use compression_config::*;
+pub use config::*;
 pub use cookie::*;
 pub use cookies::*;
 pub use data::*;
+pub use decompression_config::*;
+pub use entity_tag::*;
+pub use extension::*;
+pub use extensions::*;
+pub use form::*;
+pub use header::*;
 pub use headers::*;
+pub use http_date::*;
+#[cfg(feature = "serde")]
+pub use json::*;
+pub use media_type::*;
+pub use multipart::*;
+pub use multipart_config::*;
+pub use normalization_config::*;
+#[cfg(feature = "serde")]
+pub use pairs_deserializer::*;
 pub use params::*;
+pub use part::*;
 pub use path::*;
+#[cfg(feature = "serde")]
+pub use path_params::*;
+pub use quality_item::*;
+#[cfg(feature = "serde")]
+pub use query::*;
 pub use query_params::*;
 pub use request::*;
 pub use response::*;
+pub use response_cache::*;
+pub use rewrite_rule::*;
 pub use route::*;
+#[cfg(any(feature = "signed", feature = "private"))]
+pub use secret_key::*;
 pub use segment::*;
+#[cfg(feature = "serde")]
+pub use serde_form::*;
 pub use serwer::*;
+pub use states::*;
+pub use static_files::*;
 pub use thread_pool::*;
+pub use typed_headers::*;
 pub use worker::*;
@@ -0,0 +1,312 @@
+use crate::{
+    decode_path, is_token, split_quoted, unquote, Headers, MediaType, MultipartConfig, Part,
+    SerwerError,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Multipart {
+    parts: Vec<Part>,
+}
+
+impl Multipart {
+    pub fn from_bytes(
+        body: &[u8],
+        boundary: &str,
+        config: &MultipartConfig,
+    ) -> Result<Self, SerwerError> {
+        let delimiter = format!("--{}", boundary);
+        let separator = format!("\r\n--{}", boundary);
+
+        let mut position = find(body, delimiter.as_bytes(), 0).ok_or(
+            SerwerError::InvalidMultipart(String::from("missing boundary")),
+        )? + delimiter.len();
+        let mut parts = vec![];
+
+        loop {
+            let rest = &body[position..];
+
+            if rest.starts_with(b"--") {
+                break;
+            }
+
+            if !rest.starts_with(b"\r\n") {
+                return Err(SerwerError::InvalidMultipart(String::from(
+                    "missing CRLF after boundary",
+                )));
+            }
+
+            if parts.len() == config.max_parts() {
+                return Err(SerwerError::InvalidMultipart(String::from(
+                    "too many parts",
+                )));
+            }
+
+            position += 2;
+
+            let headers_end = find(body, b"\r\n\r\n", position).ok_or(
+                SerwerError::InvalidMultipart(String::from("missing headers end")),
+            )?;
+            let data_end = find(body, separator.as_bytes(), headers_end + 2).ok_or(
+                SerwerError::InvalidMultipart(String::from("missing closing boundary")),
+            )?;
+            let data_start = (headers_end + 4).min(data_end);
+
+            let headers_string = String::from_utf8(body[position..headers_end].to_vec())?;
+            let data = &body[data_start..data_end];
+
+            parts.push(Self::parse_part(&headers_string, data, config)?);
+
+            position = data_end + separator.len();
+        }
+
+        Ok(Self { parts })
+    }
+
+    pub fn parts(&self) -> &Vec<Part> {
+        &self.parts
+    }
+
+    pub fn part(&self, name: &str) -> Option<&Part> {
+        self.parts.iter().find(|part| part.name() == name)
+    }
+
+    pub fn fields(&self) -> Vec<&Part> {
+        self.parts.iter().filter(|part| !part.is_file()).collect()
+    }
+
+    pub fn files(&self) -> Vec<&Part> {
+        self.parts.iter().filter(|part| part.is_file()).collect()
+    }
+
+    fn parse_part(
+        headers_string: &str,
+        data: &[u8],
+        config: &MultipartConfig,
+    ) -> Result<Part, SerwerError> {
+        let mut headers = Headers::new();
+
+        for line in headers_string.split("\r\n") {
+            if !line.is_empty() {
+                let (name, value) = Self::parse_header(line)?;
+                headers.append(name, value);
+            }
+        }
+
+        let disposition =
+            headers
+                .header("content-disposition")
+                .ok_or(SerwerError::InvalidMultipart(String::from(
+                    "missing content disposition",
+                )))?;
+        let disposition_parts = split_quoted(disposition, ';');
+
+        if !disposition_parts[0].eq_ignore_ascii_case("form-data") {
+            return Err(SerwerError::InvalidMultipart(disposition.clone()));
+        }
+
+        let mut name = None;
+        let mut filename = None;
+        let mut extended_filename = None;
+
+        for parameter in disposition_parts[1..].iter() {
+            match parameter.split_once('=') {
+                Some((key, value)) if key.trim().eq_ignore_ascii_case("name") => {
+                    name = Some(unquote(value.trim()));
+                }
+                Some((key, value)) if key.trim().eq_ignore_ascii_case("filename") => {
+                    filename = Some(unquote(value.trim()));
+                }
+                Some((key, value)) if key.trim().eq_ignore_ascii_case("filename*") => {
+                    extended_filename = Self::parse_extended_value(value.trim());
+                }
+                _ => {}
+            }
+        }
+
+        let filename = extended_filename.or(filename);
+
+        let name = name.ok_or(SerwerError::InvalidMultipart(disposition.clone()))?;
+
+        if data.len() > config.max_part_size() {
+            return Err(SerwerError::PartTooLarge(name));
+        }
+
+        let content_type = match headers.header("content-type") {
+            Some(content_type) => Some(MediaType::from_string(content_type)?),
+            None => None,
+        };
+
+        Ok(Part::new(
+            &name,
+            filename,
+            content_type,
+            headers,
+            data.to_vec(),
+        ))
+    }
+
+    fn parse_header(line: &str) -> Result<(&str, &str), SerwerError> {
+        let (name, value) = line
+            .split_once(':')
+            .ok_or(SerwerError::InvalidHeader(String::from(line)))?;
+        let value = value.trim();
+
+        if !is_token(name) || value.chars().any(|c| c.is_control() && c != '\t') {
+            return Err(SerwerError::InvalidHeaderCharacters(String::from(line)));
+        }
+
+        Ok((name, value))
+    }
+
+    fn parse_extended_value(value: &str) -> Option<String> {
+        let mut parts = value.splitn(3, '\'');
+        let charset = parts.next()?;
+        let _language = parts.next()?;
+
+        if !charset.eq_ignore_ascii_case("utf-8") {
+            return None;
+        }
+
+        decode_path(parts.next()?).ok()
+    }
+}
+
+fn find(haystack: &[u8], needle: &[u8], start: usize) -> Option<usize> {
+    if start > haystack.len() {
+        return None;
+    }
+
+    haystack[start..]
+        .windows(needle.len())
+        .position(|window| window == needle)
+        .map(|position| position + start)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BODY: &str = "preamble\r\n--XyZ\r\nContent-Disposition: form-data; name=\"title\"\r\n\r\nHello\r\n--XyZ\r\nContent-Disposition: form-data; name=\"upload\"; filename=\"a; b.txt\"\r\nContent-Type: text/plain\r\n\r\nline 1\r\nline 2\r\n--XyZ--\r\n";
+
+    #[test]
+    fn test_from_bytes() {
+        let result =
+            Multipart::from_bytes(BODY.as_bytes(), "XyZ", &MultipartConfig::new()).unwrap();
+
+        assert_eq!(result.parts().len(), 2);
+
+        let title = result.part("title").unwrap();
+        assert_eq!(title.text().unwrap(), "Hello");
+        assert_eq!(title.filename(), None);
+        assert_eq!(result.fields(), vec![title]);
+
+        let upload = result.part("upload").unwrap();
+        assert_eq!(upload.filename(), Some(&String::from("a; b.txt")));
+        assert_eq!(
+            upload.content_type(),
+            Some(&MediaType::new("text", "plain"))
+        );
+        assert_eq!(
+            upload.header("Content-Type"),
+            Some(&String::from("text/plain"))
+        );
+        assert_eq!(upload.text().unwrap(), "line 1\r\nline 2");
+        assert_eq!(result.files(), vec![upload]);
+    }
+
+    #[test]
+    fn test_from_bytes_limits() {
+        let config = MultipartConfig::new().set_max_part_size(8);
+        let result = Multipart::from_bytes(BODY.as_bytes(), "XyZ", &config);
+        assert!(matches!(
+            result,
+            Err(SerwerError::PartTooLarge(error_string)) if &error_string == "upload"
+        ));
+
+        let config = MultipartConfig::new().set_max_parts(1);
+        let result = Multipart::from_bytes(BODY.as_bytes(), "XyZ", &config);
+        assert!(matches!(
+            result,
+            Err(SerwerError::InvalidMultipart(error_string)) if &error_string == "too many parts"
+        ));
+    }
+
+    #[test]
+    fn test_from_bytes_invalid() {
+        let config = MultipartConfig::new();
+
+        let result = Multipart::from_bytes(BODY.as_bytes(), "other", &config);
+        assert!(matches!(
+            result,
+            Err(SerwerError::InvalidMultipart(error_string)) if &error_string == "missing boundary"
+        ));
+
+        let result = Multipart::from_bytes(
+            b"--XyZ\r\nContent-Disposition: form-data; name=\"a\"\r\n\r\nHello",
+            "XyZ",
+            &config,
+        );
+        assert!(matches!(
+            result,
+            Err(SerwerError::InvalidMultipart(error_string)) if &error_string == "missing closing boundary"
+        ));
+
+        let result = Multipart::from_bytes(
+            b"--XyZ\r\nContent-Type: text/plain\r\n\r\nHello\r\n--XyZ--",
+            "XyZ",
+            &config,
+        );
+        assert!(matches!(
+            result,
+            Err(SerwerError::InvalidMultipart(error_string)) if &error_string == "missing content disposition"
+        ));
+    }
+
+    #[test]
+    fn test_from_bytes_empty_part() {
+        let config = MultipartConfig::new();
+
+        for body in [
+            &b"--B\r\nContent-Disposition: form-data; name=\"a\"\r\n\r\n--B--"[..],
+            &b"--B\r\nContent-Disposition: form-data; name=\"a\"\r\n\r\n\r\n--B--"[..],
+        ] {
+            let result = Multipart::from_bytes(body, "B", &config).unwrap();
+
+            assert_eq!(result.parts().len(), 1);
+            assert_eq!(result.part("a").unwrap().text().unwrap(), "");
+        }
+    }
+
+    #[test]
+    fn test_from_bytes_non_ascii_filename() {
+        let config = MultipartConfig::new();
+        let body = "--B\r\nContent-Disposition: form-data; name=\"photo\"; filename=\"zdjęcie.png\"\r\nContent-Type: image/png\r\n\r\nPNG\r\n--B\r\nContent-Disposition: form-data; name=\"scan\"; filename=\"scan.png\"; filename*=UTF-8''skan%20%C5%BC%C3%B3%C5%82ty.png\r\n\r\nPNG\r\n--B--";
+
+        let result = Multipart::from_bytes(body.as_bytes(), "B", &config).unwrap();
+
+        assert_eq!(
+            result.part("photo").unwrap().filename(),
+            Some(&String::from("zdjęcie.png"))
+        );
+        assert_eq!(
+            result.part("scan").unwrap().filename(),
+            Some(&String::from("skan żółty.png"))
+        );
+
+        let result = Multipart::from_bytes(
+            b"--B\r\nContent-Disposition: form-data; name=\"a\"\r\nX-Bad\x01: 1\r\n\r\n\r\n--B--",
+            "B",
+            &config,
+        );
+        assert!(matches!(
+            result,
+            Err(SerwerError::InvalidHeaderCharacters(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_bytes_empty() {
+        let result = Multipart::from_bytes(b"--XyZ--\r\n", "XyZ", &MultipartConfig::new());
+        assert_eq!(result.unwrap().parts().len(), 0);
+    }
+}
@@ -0,0 +1,53 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultipartConfig {
+    max_part_size: usize,
+    max_parts: usize,
+}
+
+impl MultipartConfig {
+    pub fn new() -> Self {
+        Self {
+            max_part_size: 10 * 1024 * 1024,
+            max_parts: 100,
+        }
+    }
+
+    pub fn max_part_size(&self) -> usize {
+        self.max_part_size
+    }
+
+    pub fn max_parts(&self) -> usize {
+        self.max_parts
+    }
+
+    pub fn set_max_part_size(mut self, max_part_size: usize) -> Self {
+        self.max_part_size = max_part_size;
+        self
+    }
+
+    pub fn set_max_parts(mut self, max_parts: usize) -> Self {
+        self.max_parts = max_parts;
+        self
+    }
+}
+
+impl Default for MultipartConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multipart_config_builder() {
+        let config = MultipartConfig::new()
+            .set_max_part_size(1024)
+            .set_max_parts(2);
+
+        assert_eq!(config.max_part_size(), 1024);
+        assert_eq!(config.max_parts(), 2);
+    }
+}
@@ -0,0 +1,183 @@
+use crate::TrailingSlash;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NormalizationConfig {
+    collapse_slashes: bool,
+    resolve_dot_segments: bool,
+    case_folding: bool,
+    trailing_slash: Option<TrailingSlash>,
+}
+
+impl NormalizationConfig {
+    pub fn new() -> Self {
+        Self {
+            collapse_slashes: true,
+            resolve_dot_segments: true,
+            case_folding: false,
+            trailing_slash: None,
+        }
+    }
+
+    pub fn collapse_slashes(&self) -> bool {
+        self.collapse_slashes
+    }
+
+    pub fn resolve_dot_segments(&self) -> bool {
+        self.resolve_dot_segments
+    }
+
+    pub fn case_folding(&self) -> bool {
+        self.case_folding
+    }
+
+    pub fn trailing_slash(&self) -> Option<TrailingSlash> {
+        self.trailing_slash
+    }
+
+    pub fn set_collapse_slashes(mut self, collapse_slashes: bool) -> Self {
+        self.collapse_slashes = collapse_slashes;
+        self
+    }
+
+    pub fn set_resolve_dot_segments(mut self, resolve_dot_segments: bool) -> Self {
+        self.resolve_dot_segments = resolve_dot_segments;
+        self
+    }
+
+    pub fn set_case_folding(mut self, case_folding: bool) -> Self {
+        self.case_folding = case_folding;
+        self
+    }
+
+    pub fn set_trailing_slash(mut self, trailing_slash: Option<TrailingSlash>) -> Self {
+        self.trailing_slash = trailing_slash;
+        self
+    }
+
+    pub fn normalize(&self, url: &str) -> String {
+        let (path, query) = match url.split_once('?') {
+            Some((path, query)) => (path, Some(query)),
+            None => (url, None),
+        };
+
+        let parts: Vec<&str> = path.strip_prefix('/').unwrap_or(path).split('/').collect();
+        let mut segments: Vec<&str> = vec![];
+
+        for (index, part) in parts.iter().enumerate() {
+            let is_last = index == parts.len() - 1;
+
+            if self.resolve_dot_segments && is_dot_segment(part) {
+                if is_parent_segment(part) {
+                    segments.pop();
+                }
+
+                if is_last {
+                    segments.push("");
+                }
+            } else if !(self.collapse_slashes && part.is_empty() && !is_last) {
+                segments.push(part);
+            }
+        }
+
+        let mut path = format!("/{}", segments.join("/"));
+
+        if self.case_folding {
+            path = path.to_lowercase();
+        }
+
+        match self.trailing_slash {
+            Some(TrailingSlash::Add) if !path.ends_with('/') => path.push('/'),
+            Some(TrailingSlash::Remove) if path.len() > 1 => {
+                path = format!("/{}", path.trim_matches('/'));
+            }
+            _ => (),
+        }
+
+        match query {
+            Some(query) => format!("{}?{}", path, query),
+            None => path,
+        }
+    }
+}
+
+impl Default for NormalizationConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn is_dot_segment(segment: &str) -> bool {
+    matches!(segment.to_lowercase().as_str(), "." | "%2e") || is_parent_segment(segment)
+}
+
+fn is_parent_segment(segment: &str) -> bool {
+    matches!(
+        segment.to_lowercase().as_str(),
+        ".." | ".%2e" | "%2e." | "%2e%2e"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalization_config_builder() {
+        let config = NormalizationConfig::new();
+
+        assert!(config.collapse_slashes());
+        assert!(config.resolve_dot_segments());
+        assert!(!config.case_folding());
+        assert_eq!(config.trailing_slash(), None);
+
+        let config = NormalizationConfig::new()
+            .set_collapse_slashes(false)
+            .set_resolve_dot_segments(false)
+            .set_case_folding(true)
+            .set_trailing_slash(Some(TrailingSlash::Add));
+
+        assert!(!config.collapse_slashes());
+        assert!(!config.resolve_dot_segments());
+        assert!(config.case_folding());
+        assert_eq!(config.trailing_slash(), Some(TrailingSlash::Add));
+    }
+
+    #[test]
+    fn test_normalize() {
+        let config = NormalizationConfig::new();
+
+        assert_eq!(config.normalize("/"), "/");
+        assert_eq!(config.normalize("/user//1"), "/user/1");
+        assert_eq!(config.normalize("//user///1//"), "/user/1/");
+        assert_eq!(config.normalize("/a/b/../c/./d"), "/a/c/d");
+        assert_eq!(config.normalize("/a/b/.."), "/a/");
+        assert_eq!(config.normalize("/../../etc/passwd"), "/etc/passwd");
+        assert_eq!(config.normalize("/static/%2E%2e/secret"), "/secret");
+        assert_eq!(config.normalize("/a/./?b=../c"), "/a/?b=../c");
+        assert_eq!(config.normalize("/User/1?Name=A"), "/User/1?Name=A");
+    }
+
+    #[test]
+    fn test_normalize_disabled() {
+        let config = NormalizationConfig::new()
+            .set_collapse_slashes(false)
+            .set_resolve_dot_segments(false);
+
+        assert_eq!(config.normalize("/user//1/../2"), "/user//1/../2");
+    }
+
+    #[test]
+    fn test_normalize_case_folding_and_trailing_slash() {
+        let config = NormalizationConfig::new()
+            .set_case_folding(true)
+            .set_trailing_slash(Some(TrailingSlash::Add));
+
+        assert_eq!(config.normalize("/User/1?Name=A"), "/user/1/?Name=A");
+        assert_eq!(config.normalize("/"), "/");
+
+        let config = NormalizationConfig::new().set_trailing_slash(Some(TrailingSlash::Remove));
+
+        assert_eq!(config.normalize("/user/1/"), "/user/1");
+        assert_eq!(config.normalize("/"), "/");
+    }
+}
@@ -0,0 +1,348 @@
+use crate::SerwerError;
+use serde::{
+    de::{
+        self,
+        value::{Error, MapDeserializer, SeqDeserializer},
+        DeserializeOwned, IntoDeserializer, Unexpected, Visitor,
+    },
+    forward_to_deserialize_any,
+};
+use std::collections::HashMap;
+
+macro_rules! forward_to_value {
+    ($($method:ident)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                self.value().$method(visitor)
+            }
+        )*
+    };
+}
+
+macro_rules! parse_value {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                match self.0.parse() {
+                    Ok(value) => visitor.$visit(value),
+                    Err(_) => Err(de::Error::invalid_value(Unexpected::Str(&self.0), &visitor)),
+                }
+            }
+        )*
+    };
+}
+
+pub struct PairsDeserializer {
+    pairs: Vec<(String, Vec<String>)>,
+}
+
+impl PairsDeserializer {
+    pub(crate) fn new<'a>(pairs: impl IntoIterator<Item = (&'a String, &'a String)>) -> Self {
+        let mut grouped: Vec<(String, Vec<String>)> = vec![];
+        let mut indexes: HashMap<&str, usize> = HashMap::new();
+
+        for (name, value) in pairs {
+            match indexes.get(name.as_str()) {
+                Some(&index) => grouped[index].1.push(value.clone()),
+                None => {
+                    indexes.insert(name, grouped.len());
+                    grouped.push((name.clone(), vec![value.clone()]));
+                }
+            }
+        }
+
+        Self { pairs: grouped }
+    }
+
+    pub(crate) fn deserialize<'a, T: DeserializeOwned>(
+        pairs: impl IntoIterator<Item = (&'a String, &'a String)>,
+    ) -> Result<T, SerwerError> {
+        T::deserialize(Self::new(pairs))
+            .map_err(|error| SerwerError::DeserializeError(error.to_string()))
+    }
+}
+
+impl<'de> de::Deserializer<'de> for PairsDeserializer {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_map(MapDeserializer::new(
+            self.pairs
+                .into_iter()
+                .map(|(name, values)| (name, ValuesDeserializer(values))),
+        ))
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+struct ValuesDeserializer(Vec<String>);
+
+impl ValuesDeserializer {
+    fn value(self) -> ValueDeserializer {
+        ValueDeserializer(self.0.into_iter().next().unwrap_or_default())
+    }
+}
+
+impl<'de> IntoDeserializer<'de, Error> for ValuesDeserializer {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+impl<'de> de::Deserializer<'de> for ValuesDeserializer {
+    type Error = Error;
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_seq(SeqDeserializer::new(
+            self.0.into_iter().map(ValueDeserializer),
+        ))
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, _: usize, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: usize,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.value().deserialize_unit_struct(name, visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.value().deserialize_newtype_struct(name, visitor)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.value().deserialize_struct(name, fields, visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.value().deserialize_enum(name, variants, visitor)
+    }
+
+    forward_to_value! {
+        deserialize_any deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32
+        deserialize_i64 deserialize_i128 deserialize_u8 deserialize_u16 deserialize_u32
+        deserialize_u64 deserialize_u128 deserialize_f32 deserialize_f64 deserialize_char
+        deserialize_str deserialize_string deserialize_bytes deserialize_byte_buf
+        deserialize_option deserialize_unit deserialize_map deserialize_identifier
+        deserialize_ignored_any
+    }
+}
+
+struct ValueDeserializer(String);
+
+impl<'de> IntoDeserializer<'de, Error> for ValueDeserializer {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+impl<'de> de::Deserializer<'de> for ValueDeserializer {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_string(self.0)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        if self.0.is_empty() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_enum(self.0.into_deserializer())
+    }
+
+    parse_value! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    }
+
+    forward_to_deserialize_any! {
+        str string bytes byte_buf unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use std::collections::HashMap;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(rename_all = "lowercase")]
+    enum Order {
+        Asc,
+        Desc,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Search {
+        q: String,
+        page: u32,
+        exact: bool,
+        order: Order,
+        tags: Vec<String>,
+        limit: Option<u8>,
+        offset: Option<u8>,
+    }
+
+    fn pairs(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(name, value)| (String::from(*name), String::from(*value)))
+            .collect()
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let pairs = pairs(&[
+            ("q", "rust"),
+            ("tags", "web"),
+            ("page", "2"),
+            ("exact", "true"),
+            ("order", "desc"),
+            ("tags", "http"),
+            ("limit", ""),
+            ("offset", "10"),
+        ]);
+
+        let result: Search =
+            PairsDeserializer::deserialize(pairs.iter().map(|(name, value)| (name, value)))
+                .unwrap();
+
+        assert_eq!(
+            result,
+            Search {
+                q: String::from("rust"),
+                page: 2,
+                exact: true,
+                order: Order::Desc,
+                tags: vec![String::from("web"), String::from("http")],
+                limit: None,
+                offset: Some(10),
+            }
+        );
+    }
+
+    #[test]
+    fn test_deserialize_map() {
+        let pairs = pairs(&[("id", "1"), ("name", "John"), ("id", "2")]);
+
+        let result: HashMap<String, String> =
+            PairsDeserializer::deserialize(pairs.iter().map(|(name, value)| (name, value)))
+                .unwrap();
+
+        assert_eq!(result.get("id"), Some(&String::from("1")));
+        assert_eq!(result.get("name"), Some(&String::from("John")));
+    }
+
+    #[test]
+    fn test_new_groups_in_order() {
+        let mut pairs: Vec<(String, String)> = (0..20_000)
+            .map(|index| (format!("a{}", index), String::new()))
+            .collect();
+        pairs.push((String::from("a1"), String::from("x")));
+
+        let result = PairsDeserializer::new(pairs.iter().map(|(name, value)| (name, value)));
+
+        assert_eq!(result.pairs.len(), 20_000);
+        assert_eq!(result.pairs[0], (String::from("a0"), vec![String::new()]));
+        assert_eq!(
+            result.pairs[1],
+            (String::from("a1"), vec![String::new(), String::from("x")])
+        );
+        assert_eq!(result.pairs[19_999].0, "a19999");
+    }
+
+    #[test]
+    fn test_deserialize_invalid() {
+        let pairs = pairs(&[("q", "rust"), ("page", "two")]);
+
+        let result: Result<Search, SerwerError> =
+            PairsDeserializer::deserialize(pairs.iter().map(|(name, value)| (name, value)));
+
+        assert!(matches!(
+            result,
+            Err(SerwerError::DeserializeError(error_string)) if error_string.contains(r#"invalid value: string "two", expected u32"#)
+        ));
+
+        let pairs = self::pairs(&[("page", "2")]);
+
+        let result: Result<Search, SerwerError> =
+            PairsDeserializer::deserialize(pairs.iter().map(|(name, value)| (name, value)));
+
+        assert!(matches!(
+            result,
+            Err(SerwerError::DeserializeError(error_string)) if error_string == "missing field `q`"
+        ));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::encode;
 use std::collections::HashMap;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -16,9 +17,31 @@ impl Params {
         self.params.get(key)
     }
 
+    #[cfg(feature = "serde")]
+    pub(crate) fn params(&self) -> &HashMap<String, String> {
+        &self.params
+    }
+
     pub fn set_param(&mut self, key: &str, value: &str) {
         self.params.insert(key.to_string(), value.to_string());
     }
+
+    pub(crate) fn fill(&self, template: &str) -> Option<String> {
+        let mut result = String::new();
+        let mut rest = template;
+
+        while let Some((before, after)) = rest.split_once('<') {
+            let (name, after) = after.split_once('>')?;
+
+            result.push_str(before);
+            result.push_str(&encode(self.param(name)?));
+            rest = after;
+        }
+
+        result.push_str(rest);
+
+        Some(result)
+    }
 }
 
 #[cfg(test)]
@@ -45,4 +68,16 @@ mod tests {
         assert_eq!(params.params.len(), 1);
         assert_eq!(params.param("user").unwrap(), "1");
     }
+
+    #[test]
+    fn test_fill() {
+        let mut params = Params::new();
+        params.set_param("id", "a,b");
+        assert_eq!(
+            params.fill("/user/<id>/posts").unwrap(),
+            "/user/a%2Cb/posts"
+        );
+        assert_eq!(params.fill("/user"), Some(String::from("/user")));
+        assert_eq!(params.fill("/user/<name>"), None);
+    }
 }
@@ -0,0 +1,105 @@
+use crate::{Headers, MediaType, SerwerError};
+use std::fs;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Part {
+    name: String,
+    filename: Option<String>,
+    content_type: Option<MediaType>,
+    headers: Headers,
+    data: Vec<u8>,
+}
+
+impl Part {
+    pub fn new(
+        name: &str,
+        filename: Option<String>,
+        content_type: Option<MediaType>,
+        headers: Headers,
+        data: Vec<u8>,
+    ) -> Self {
+        Self {
+            name: String::from(name),
+            filename,
+            content_type,
+            headers,
+            data,
+        }
+    }
+
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn filename(&self) -> Option<&String> {
+        self.filename.as_ref()
+    }
+
+    pub fn content_type(&self) -> Option<&MediaType> {
+        self.content_type.as_ref()
+    }
+
+    pub fn header(&self, name: &str) -> Option<&String> {
+        self.headers.header(name)
+    }
+
+    pub fn is_file(&self) -> bool {
+        self.filename.is_some()
+    }
+
+    pub fn data(&self) -> &Vec<u8> {
+        &self.data
+    }
+
+    pub fn text(&self) -> Result<String, SerwerError> {
+        Ok(String::from_utf8(self.data.clone())?)
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), SerwerError> {
+        fs::write(path, &self.data)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_field_part() {
+        let part = Part::new(
+            "comment",
+            None,
+            None,
+            Headers::new(),
+            b"Hello World".to_vec(),
+        );
+
+        assert_eq!(part.name(), "comment");
+        assert!(!part.is_file());
+        assert_eq!(part.text().unwrap(), "Hello World");
+    }
+
+    #[test]
+    fn test_file_part() {
+        let part = Part::new(
+            "upload",
+            Some(String::from("hello.txt")),
+            Some(MediaType::new("text", "plain")),
+            Headers::new(),
+            b"Hello World".to_vec(),
+        );
+
+        assert!(part.is_file());
+        assert_eq!(part.filename(), Some(&String::from("hello.txt")));
+        assert_eq!(part.content_type(), Some(&MediaType::new("text", "plain")));
+        assert_eq!(part.data(), b"Hello World");
+
+        let saved = env::temp_dir().join("serwer-test-part-save.txt");
+        part.save(saved.to_str().unwrap()).unwrap();
+        assert_eq!(fs::read(&saved).unwrap(), b"Hello World");
+        fs::remove_file(saved).unwrap();
+    }
+}
@@ -87,6 +87,31 @@ impl Path {
         (true, Some(params))
     }
 
+    pub(crate) fn strip_prefix(&self, prefix: &Path) -> Option<Vec<String>> {
+        let prefix_segments: Vec<&Segment> = prefix
+            .segments
+            .iter()
+            .filter(|segment| !segment.name().is_empty())
+            .collect();
+
+        if self.segments.len() < prefix_segments.len() {
+            return None;
+        }
+
+        for (segment, prefix_segment) in self.segments.iter().zip(&prefix_segments) {
+            if segment.name() != prefix_segment.name() {
+                return None;
+            }
+        }
+
+        Some(
+            self.segments[prefix_segments.len()..]
+                .iter()
+                .map(|segment| segment.name().clone())
+                .collect(),
+        )
+    }
+
     pub fn original_url(&self) -> &String {
         &self.original_url
     }
@@ -106,6 +131,15 @@ impl Path {
     pub fn query_param(&self, key: &str) -> Option<&String> {
         self.query_params.query_param(key)
     }
+
+    pub fn query_params_all(&self, key: &str) -> Vec<&String> {
+        self.query_params.query_params_all(key)
+    }
+
+    #[cfg(feature = "serde")]
+    pub(crate) fn query_params(&self) -> &QueryParams {
+        &self.query_params
+    }
 }
 
 #[cfg(test)]
@@ -185,8 +219,13 @@ mod tests {
 
         let string = &String::from("/user?");
         let result = Path::from_string(string);
-        assert!(
-            matches!(result, Err(SerwerError::InvalidQueryParam(error_string)) if &error_string == "")
+        assert_eq!(
+            result.unwrap(),
+            Path {
+                original_url: String::from("/user?"),
+                segments: vec![Segment::from_string("user").unwrap()],
+                query_params: QueryParams::new()
+            }
         );
     }
 
@@ -359,4 +398,42 @@ mod tests {
         let result = path.matches(&other_path);
         assert_eq!(result, (false, None));
     }
+
+    #[test]
+    fn test_strip_prefix() {
+        let path = Path::from_string("/static/css/main.css").unwrap();
+
+        assert_eq!(
+            path.strip_prefix(&Path::from_string("/static").unwrap()),
+            Some(vec![String::from("css"), String::from("main.css")])
+        );
+        assert_eq!(
+            path.strip_prefix(&Path::from_string("/static/").unwrap()),
+            Some(vec![String::from("css"), String::from("main.css")])
+        );
+        assert_eq!(
+            path.strip_prefix(&Path::from_string("/").unwrap()),
+            Some(vec![
+                String::from("static"),
+                String::from("css"),
+                String::from("main.css")
+            ])
+        );
+        assert_eq!(
+            path.strip_prefix(&Path::from_string("/assets").unwrap()),
+            None
+        );
+        assert_eq!(
+            Path::from_string("/static")
+                .unwrap()
+                .strip_prefix(&Path::from_string("/static").unwrap()),
+            Some(vec![])
+        );
+        assert_eq!(
+            Path::from_string("/static/")
+                .unwrap()
+                .strip_prefix(&Path::from_string("/static").unwrap()),
+            Some(vec![String::from("")])
+        );
+    }
 }
@@ -0,0 +1,38 @@
+use crate::{FromRequest, Request, SerwerError};
+use serde::de::DeserializeOwned;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PathParams<T>(pub T);
+
+impl<T: DeserializeOwned> FromRequest for PathParams<T> {
+    fn from_request(request: &Request) -> Result<Self, SerwerError> {
+        Ok(Self(request.params()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{request_from_bytes, Params};
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct TaskParams {
+        id: u32,
+    }
+
+    #[test]
+    fn test_from_request() {
+        let mut request = request_from_bytes(b"GET /task/1 HTTP/1.1\r\n\r\n").unwrap();
+
+        let mut params = Params::new();
+        params.set_param("id", "1");
+        request.set_params(params);
+
+        assert_eq!(
+            PathParams::<TaskParams>::from_request(&request).unwrap(),
+            PathParams(TaskParams { id: 1 })
+        );
+        assert!(PathParams::<(u32,)>::from_request(&request).is_err());
+    }
+}
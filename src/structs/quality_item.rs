@@ -0,0 +1,141 @@
+use crate::{split_quoted, SerwerError};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QualityItem<T> {
+    item: T,
+    quality: u16,
+}
+
+impl<T> QualityItem<T> {
+    pub fn new(item: T, quality: u16) -> Self {
+        Self {
+            item,
+            quality: quality.min(1000),
+        }
+    }
+
+    pub fn list_from_string<F>(string: &str, parse: F) -> Result<Vec<Self>, SerwerError>
+    where
+        F: Fn(&str) -> Result<T, SerwerError>,
+    {
+        let mut items = vec![];
+
+        for element in split_quoted(string, ',') {
+            if element.is_empty() {
+                continue;
+            }
+
+            let mut params = split_quoted(&element, ';');
+            let mut quality = 1000;
+
+            if let Some(position) = params
+                .iter()
+                .position(|param| param.to_lowercase().starts_with("q="))
+            {
+                quality = parse_quality(&params[position][2..])
+                    .ok_or(SerwerError::InvalidHeaderValue(element.clone()))?;
+                params.truncate(position);
+            }
+
+            items.push(Self::new(parse(&params.join("; "))?, quality));
+        }
+
+        Ok(items)
+    }
+
+    pub fn item(&self) -> &T {
+        &self.item
+    }
+
+    pub fn quality(&self) -> u16 {
+        self.quality
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for QualityItem<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.item)?;
+
+        match self.quality {
+            1000 => Ok(()),
+            0 => write!(f, "; q=0"),
+            quality => {
+                let decimals = format!("{:03}", quality);
+                write!(f, "; q=0.{}", decimals.trim_end_matches('0'))
+            }
+        }
+    }
+}
+
+fn parse_quality(string: &str) -> Option<u16> {
+    let (integer, decimals) = string.split_once('.').unwrap_or((string, ""));
+
+    if decimals.len() > 3 || !decimals.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    match integer {
+        "0" => Some(format!("{:0<3}", decimals).parse().ok()?),
+        "1" if decimals.chars().all(|c| c == '0') => Some(1000),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(string: &str) -> Result<String, SerwerError> {
+        Ok(String::from(string))
+    }
+
+    #[test]
+    fn test_list_from_string() {
+        let result = QualityItem::list_from_string("gzip;q=0.8, br, identity; q=0", parse);
+        assert_eq!(
+            result.unwrap(),
+            vec![
+                QualityItem::new(String::from("gzip"), 800),
+                QualityItem::new(String::from("br"), 1000),
+                QualityItem::new(String::from("identity"), 0),
+            ]
+        );
+
+        let result = QualityItem::list_from_string("text/html;level=1;q=0.55, , */*", parse);
+        assert_eq!(
+            result.unwrap(),
+            vec![
+                QualityItem::new(String::from("text/html; level=1"), 550),
+                QualityItem::new(String::from("*/*"), 1000),
+            ]
+        );
+
+        let result = QualityItem::list_from_string("", parse);
+        assert_eq!(result.unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_list_from_string_invalid_quality() {
+        for string in [
+            "gzip;q=2",
+            "gzip;q=0.1234",
+            "gzip;q=",
+            "gzip;q=1.5",
+            "gzip;q=x",
+        ] {
+            assert!(matches!(
+                QualityItem::list_from_string(string, parse),
+                Err(SerwerError::InvalidHeaderValue(error_string)) if error_string == string
+            ));
+        }
+    }
+
+    #[test]
+    fn test_to_string() {
+        assert_eq!(QualityItem::new("gzip", 1000).to_string(), "gzip");
+        assert_eq!(QualityItem::new("gzip", 800).to_string(), "gzip; q=0.8");
+        assert_eq!(QualityItem::new("gzip", 125).to_string(), "gzip; q=0.125");
+        assert_eq!(QualityItem::new("gzip", 0).to_string(), "gzip; q=0");
+    }
+}
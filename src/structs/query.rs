@@ -0,0 +1,44 @@
+use crate::{FromRequest, Request, SerwerError};
+use serde::de::DeserializeOwned;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Query<T>(pub T);
+
+impl<T: DeserializeOwned> FromRequest for Query<T> {
+    fn from_request(request: &Request) -> Result<Self, SerwerError> {
+        Ok(Self(request.query()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request_from_bytes;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Pagination {
+        page: u32,
+        per_page: Option<u32>,
+    }
+
+    #[test]
+    fn test_from_request() {
+        let request = request_from_bytes(b"GET /?page=2 HTTP/1.1\r\n\r\n").unwrap();
+
+        assert_eq!(
+            Query::<Pagination>::from_request(&request).unwrap(),
+            Query(Pagination {
+                page: 2,
+                per_page: None
+            })
+        );
+
+        let request = request_from_bytes(b"GET /?page=two HTTP/1.1\r\n\r\n").unwrap();
+
+        assert!(matches!(
+            Query::<Pagination>::from_request(&request),
+            Err(SerwerError::DeserializeError(_))
+        ));
+    }
+}
@@ -1,8 +1,7 @@
-use crate::{decode, SerwerError};
-use std::collections::HashMap;
+use crate::{decode_query, SerwerError};
 
 const NAME_ALLOWED_CHARACTERS: &str =
-    "-.0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ_abcdefghijklmnopqrstuvwxyz~";
+    "%-.0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ[]_abcdefghijklmnopqrstuvwxyz~";
 
 const VALUE_ALLOWED_CHARACTERS: &str =
     "%+-.0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ_abcdefghijklmnopqrstuvwxyz~";
@@ -12,56 +11,82 @@ const VALUE_ALLOWED_CHARACTERS_WITH_RESERVED: &str =
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct QueryParams {
-    query_params: HashMap<String, String>,
+    query_params: Vec<(String, String)>,
 }
 
 impl QueryParams {
     pub fn new() -> Self {
         Self {
-            query_params: HashMap::new(),
+            query_params: vec![],
         }
     }
 
     pub fn from_string(string: &str) -> Result<Self, SerwerError> {
-        let mut query_params = HashMap::new();
+        let mut query_params = vec![];
 
-        let parts: Vec<String> = string.split("&").map(String::from).collect();
+        for part in string.split('&') {
+            if part.is_empty() {
+                continue;
+            }
 
-        for part in parts.iter() {
-            let (name, value) = part
-                .split_once('=')
-                .ok_or(SerwerError::InvalidQueryParam(String::from(part)))?;
+            let (name, value) = part.split_once('=').unwrap_or((part, ""));
             let value = value.trim();
 
-            if name.is_empty() || value.is_empty() {
+            if name.is_empty() {
                 return Err(SerwerError::InvalidQueryParam(String::from(part)));
             }
 
-            if !name.chars().all(|c| NAME_ALLOWED_CHARACTERS.contains(c)) {
+            if !name
+                .chars()
+                .all(|c| NAME_ALLOWED_CHARACTERS.contains(c) || !c.is_ascii())
+            {
                 return Err(SerwerError::InvalidQueryParamCharacters(String::from(part)));
             }
 
-            if !value.chars().all(|c| VALUE_ALLOWED_CHARACTERS.contains(c)) {
+            if !value
+                .chars()
+                .all(|c| VALUE_ALLOWED_CHARACTERS.contains(c) || !c.is_ascii())
+            {
                 return Err(SerwerError::InvalidQueryParamCharacters(String::from(part)));
             }
 
-            let value = decode(value)?;
+            let name = decode_query(name)?;
+            let value = decode_query(value)?;
 
-            if !value
-                .chars()
-                .all(|c| VALUE_ALLOWED_CHARACTERS_WITH_RESERVED.contains(c))
-            {
+            if !value.chars().all(|c| {
+                VALUE_ALLOWED_CHARACTERS_WITH_RESERVED.contains(c)
+                    || !(c.is_ascii() || c.is_control())
+            }) {
                 return Err(SerwerError::InvalidQueryParamCharacters(String::from(part)));
             }
 
-            query_params.insert(String::from(name), String::from(value));
+            query_params.push((String::from(Self::key(&name)), value));
         }
 
         Ok(Self { query_params })
     }
 
     pub fn query_param(&self, key: &str) -> Option<&String> {
-        self.query_params.get(key)
+        self.query_params_all(key).into_iter().next()
+    }
+
+    pub fn query_params_all(&self, key: &str) -> Vec<&String> {
+        let key = Self::key(key);
+
+        self.query_params
+            .iter()
+            .filter(|(name, _)| name == key)
+            .map(|(_, value)| value)
+            .collect()
+    }
+
+    #[cfg(feature = "serde")]
+    pub(crate) fn query_params(&self) -> &Vec<(String, String)> {
+        &self.query_params
+    }
+
+    fn key(name: &str) -> &str {
+        name.strip_suffix("[]").unwrap_or(name)
     }
 }
 
@@ -74,10 +99,8 @@ mod tests {
         let string = &String::from("id=1");
         let result = QueryParams::from_string(string);
 
-        let mut hashmap = HashMap::new();
-        hashmap.insert(String::from("id"), String::from("1"));
-        let query_params = QueryParams {
-            query_params: hashmap.clone(),
+        let mut query_params = QueryParams {
+            query_params: vec![(String::from("id"), String::from("1"))],
         };
 
         assert_eq!(result.unwrap(), query_params.clone());
@@ -85,10 +108,9 @@ mod tests {
         let string = &String::from("id=1&name=John+Doe");
         let result = QueryParams::from_string(string);
 
-        hashmap.insert(String::from("name"), String::from("John Doe"));
-        let query_params = QueryParams {
-            query_params: hashmap,
-        };
+        query_params
+            .query_params
+            .push((String::from("name"), String::from("John Doe")));
 
         assert_eq!(result.unwrap(), query_params.clone());
 
@@ -99,14 +121,58 @@ mod tests {
     }
 
     #[test]
-    fn test_from_string_invalid_characters() {
-        let string = &String::from("name=Jo€hn");
-        let result = QueryParams::from_string(string);
-        assert!(matches!(
-            result,
-            Err(SerwerError::InvalidQueryParamCharacters(error_string)) if &error_string == "name=Jo€hn"
-        ));
+    fn test_from_string_utf8() {
+        let string = &String::from("name=Ren%C3%A9e&city=Łódź&sum=1%2B1");
+        let result = QueryParams::from_string(string).unwrap();
+
+        assert_eq!(result.query_param("name"), Some(&String::from("Renée")));
+        assert_eq!(result.query_param("city"), Some(&String::from("Łódź")));
+        assert_eq!(result.query_param("sum"), Some(&String::from("1+1")));
+    }
+
+    #[test]
+    fn test_from_string_repeated() {
+        let string = &String::from("tag=a&id=1&tag=b");
+        let result = QueryParams::from_string(string).unwrap();
+
+        assert_eq!(result.query_param("tag"), Some(&String::from("a")));
+        assert_eq!(
+            result.query_params_all("tag"),
+            vec![&String::from("a"), &String::from("b")]
+        );
+        assert_eq!(result.query_params_all("name"), Vec::<&String>::new());
+    }
 
+    #[test]
+    fn test_from_string_brackets() {
+        let string = &String::from("ids[]=1&ids%5B%5D=2&ids=3&name=John");
+        let result = QueryParams::from_string(string).unwrap();
+
+        let ids = [String::from("1"), String::from("2"), String::from("3")];
+        assert_eq!(
+            result.query_params_all("ids"),
+            ids.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            result.query_params_all("ids[]"),
+            ids.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(result.query_param("ids[]"), Some(&ids[0]));
+        assert_eq!(result.query_params.len(), 4);
+    }
+
+    #[test]
+    fn test_from_string_empty_values() {
+        let string = &String::from("flag&q=&id=1");
+        let result = QueryParams::from_string(string).unwrap();
+
+        assert_eq!(result.query_param("flag"), Some(&String::from("")));
+        assert_eq!(result.query_param("q"), Some(&String::from("")));
+        assert_eq!(result.query_param("id"), Some(&String::from("1")));
+    }
+
+    #[test]
+    fn test_from_string_invalid_characters() {
         let string = &String::from("na@me=John");
         let result = QueryParams::from_string(string);
         assert!(matches!(
@@ -130,37 +196,36 @@ mod tests {
 
         let string = &String::from("name=Jo%hn");
         let result = QueryParams::from_string(string);
-        assert!(matches!(result, Err(SerwerError::ParseIntError(_))));
+        assert!(matches!(
+            result,
+            Err(SerwerError::DecodeError(error_string)) if &error_string == "Jo%hn"
+        ));
     }
 
     #[test]
-    fn test_from_string_invalid_ampersands() {
+    fn test_from_string_ampersands() {
+        let expected = QueryParams {
+            query_params: vec![(String::from("id"), String::from("1"))],
+        };
+
         let string = &String::from("&id=1");
         let result = QueryParams::from_string(string);
-        assert!(
-            matches!(result, Err(SerwerError::InvalidQueryParam(error_string)) if &error_string == "")
-        );
+        assert_eq!(result.unwrap(), expected);
 
         let string = &String::from("id=1&");
         let result = QueryParams::from_string(string);
-        assert!(
-            matches!(result, Err(SerwerError::InvalidQueryParam(error_string)) if &error_string == "")
-        );
+        assert_eq!(result.unwrap(), expected);
 
         let string = &String::from("id=1&&name=John");
         let result = QueryParams::from_string(string);
-        assert!(
-            matches!(result, Err(SerwerError::InvalidQueryParam(error_string)) if &error_string == "")
-        );
+        assert_eq!(result.unwrap().query_params.len(), 2);
     }
 
     #[test]
     fn test_from_string_empty() {
         let string = &String::from("");
         let result = QueryParams::from_string(string);
-        assert!(
-            matches!(result, Err(SerwerError::InvalidQueryParam(error_string)) if &error_string == "")
-        );
+        assert_eq!(result.unwrap(), QueryParams::new());
 
         let string = &String::from("=");
         let result = QueryParams::from_string(string);
@@ -171,22 +236,16 @@ mod tests {
 
     #[test]
     fn test_from_string_invalid_query_param() {
-        let string = &String::from("id=");
-        let result = QueryParams::from_string(string);
-        assert!(
-            matches!(result, Err(SerwerError::InvalidQueryParam(error_string)) if &error_string == "id=")
-        );
-
         let string = &String::from("=1");
         let result = QueryParams::from_string(string);
         assert!(
             matches!(result, Err(SerwerError::InvalidQueryParam(error_string)) if &error_string == "=1")
         );
 
-        let string = &String::from("id=1&name=");
+        let string = &String::from("id=1&=John");
         let result = QueryParams::from_string(string);
         assert!(
-            matches!(result, Err(SerwerError::InvalidQueryParam(error_string)) if &error_string == "name=")
+            matches!(result, Err(SerwerError::InvalidQueryParam(error_string)) if &error_string == "=John")
         );
     }
 }
@@ -1,4 +1,16 @@
-use crate::{Cookie, Cookies, Headers, Method, Params, Path, SerwerError, Version};
+#[cfg(feature = "serde")]
+use crate::PairsDeserializer;
+#[cfg(any(feature = "signed", feature = "private"))]
+use crate::SecretKey;
+use crate::{
+    Accept, AcceptCharset, AcceptEncoding, AcceptLanguage, ContentEncoding, ContentType, Cookie,
+    CookieEncoding, Cookies, Data, DecompressionConfig, EntityTag, EntityTagList, Extensions, Form,
+    FromForm, Headers, HttpDate, IfMatch, IfModifiedSince, IfNoneMatch, IfUnmodifiedSince,
+    MediaType, Method, Multipart, MultipartConfig, NormalizationConfig, Params, Path, QualityItem,
+    RewriteRule, SerwerError, States, StatusCode, TypedHeader, Version,
+};
+#[cfg(feature = "serde")]
+use serde::de::DeserializeOwned;
 use std::{
     io::{BufRead, BufReader, Read},
     net::TcpStream,
@@ -13,10 +25,18 @@ pub struct Request {
     cookies: Cookies,
     body: Vec<u8>,
     params: Params,
+    states: States,
+    extensions: Extensions,
+    cookie_encoding: CookieEncoding,
+    #[cfg(any(feature = "signed", feature = "private"))]
+    secret_keys: Vec<SecretKey>,
 }
 
 impl Request {
-    pub(crate) fn from_stream(stream: &TcpStream) -> Result<Self, SerwerError> {
+    pub(crate) fn from_stream(
+        stream: &TcpStream,
+        max_body_size: usize,
+    ) -> Result<Self, SerwerError> {
         let mut buf_reader = BufReader::new(stream);
         let buffer = &mut String::new();
 
@@ -66,9 +86,11 @@ impl Request {
         }
 
         let cookies_string = headers
-            .header("cookie")
-            .map(|s| s.to_owned())
-            .unwrap_or_default();
+            .header_all("cookie")
+            .into_iter()
+            .map(String::as_str)
+            .collect::<Vec<&str>>()
+            .join("; ");
         let cookies = Cookies::from_string(&cookies_string)?;
 
         let content_length: usize = headers
@@ -78,6 +100,10 @@ impl Request {
             .unwrap_or_default();
         let mut body: Vec<u8> = vec![];
 
+        if content_length > max_body_size {
+            return Err(SerwerError::BodyTooLarge(max_body_size.to_string()));
+        }
+
         if content_length > 0 {
             let mut body_buffer = vec![0; content_length];
             buf_reader.read_exact(&mut body_buffer)?;
@@ -92,6 +118,11 @@ impl Request {
             cookies,
             body,
             params: Params::new(),
+            states: States::new(),
+            extensions: Extensions::new(),
+            cookie_encoding: CookieEncoding::Raw,
+            #[cfg(any(feature = "signed", feature = "private"))]
+            secret_keys: vec![],
         })
     }
 
@@ -107,6 +138,25 @@ impl Request {
         self.path.query_param(key).cloned()
     }
 
+    pub fn query_params_all(&self, key: &str) -> Vec<String> {
+        self.path
+            .query_params_all(key)
+            .into_iter()
+            .cloned()
+            .collect()
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn query<T: DeserializeOwned>(&self) -> Result<T, SerwerError> {
+        PairsDeserializer::deserialize(
+            self.path
+                .query_params()
+                .query_params()
+                .iter()
+                .map(|(name, value)| (name, value)),
+        )
+    }
+
     pub fn version(&self) -> Version {
         self.version.to_owned()
     }
@@ -115,8 +165,191 @@ impl Request {
         self.headers.header(key).cloned()
     }
 
+    pub fn header_all(&self, key: &str) -> Vec<String> {
+        self.headers.header_all(key).into_iter().cloned().collect()
+    }
+
+    pub fn header_joined(&self, key: &str) -> Option<String> {
+        self.headers.header_joined(key)
+    }
+
+    pub fn typed_header<T: TypedHeader>(&self) -> Option<T> {
+        T::from_value(&self.headers.header_joined(T::name())?).ok()
+    }
+
+    pub fn accepts(&self, available: &[&str]) -> Result<String, StatusCode> {
+        let accept = self.typed_header::<Accept>();
+
+        Self::negotiate(available, |available| {
+            let (Some(accept), Ok(media_type)) = (&accept, MediaType::from_string(available))
+            else {
+                return Some(1000);
+            };
+
+            accept
+                .0
+                .iter()
+                .filter(|item| item.item().matches(&media_type))
+                .max_by_key(|item| {
+                    (item.item().main_type() != "*") as u8 + (item.item().sub_type() != "*") as u8
+                })
+                .map(QualityItem::quality)
+        })
+    }
+
+    pub fn accepts_language(&self, available: &[&str]) -> Result<String, StatusCode> {
+        let accept_language = self.typed_header::<AcceptLanguage>();
+
+        Self::negotiate(available, |available| {
+            let Some(accept_language) = &accept_language else {
+                return Some(1000);
+            };
+
+            accept_language
+                .0
+                .iter()
+                .filter(|item| {
+                    let range = item.item();
+
+                    range == "*"
+                        || range.eq_ignore_ascii_case(available)
+                        || available.get(..range.len() + 1).is_some_and(|prefix| {
+                            prefix.eq_ignore_ascii_case(&format!("{}-", range))
+                        })
+                })
+                .max_by_key(|item| {
+                    if item.item() == "*" {
+                        0
+                    } else {
+                        item.item().len()
+                    }
+                })
+                .map(QualityItem::quality)
+        })
+    }
+
+    pub fn accepts_encoding(&self, available: &[&str]) -> Result<String, StatusCode> {
+        let accept_encoding = self.typed_header::<AcceptEncoding>();
+
+        Self::negotiate(available, |available| {
+            let Some(accept_encoding) = &accept_encoding else {
+                return Some(1000);
+            };
+
+            Self::token_quality(&accept_encoding.0, available)
+                .or(available.eq_ignore_ascii_case("identity").then_some(1000))
+        })
+    }
+
+    pub fn accepts_charset(&self, available: &[&str]) -> Result<String, StatusCode> {
+        let accept_charset = self.typed_header::<AcceptCharset>();
+
+        Self::negotiate(available, |available| match &accept_charset {
+            Some(accept_charset) => Self::token_quality(&accept_charset.0, available),
+            None => Some(1000),
+        })
+    }
+
+    fn negotiate<F>(available: &[&str], quality: F) -> Result<String, StatusCode>
+    where
+        F: Fn(&str) -> Option<u16>,
+    {
+        let mut best: Option<(&str, u16)> = None;
+
+        for item in available {
+            let quality = quality(item).unwrap_or_default();
+
+            if quality > 0 && best.is_none_or(|(_, best_quality)| quality > best_quality) {
+                best = Some((item, quality));
+            }
+        }
+
+        best.map(|(item, _)| String::from(item))
+            .ok_or(StatusCode::NotAcceptable)
+    }
+
+    fn token_quality(items: &[QualityItem<String>], available: &str) -> Option<u16> {
+        items
+            .iter()
+            .find(|item| item.item().eq_ignore_ascii_case(available))
+            .or(items.iter().find(|item| item.item() == "*"))
+            .map(QualityItem::quality)
+    }
+
+    pub fn preconditions(
+        &self,
+        etag: Option<&EntityTag>,
+        last_modified: Option<HttpDate>,
+    ) -> Result<(), StatusCode> {
+        if let Some(if_match) = self.typed_header::<IfMatch>() {
+            let matches = match (&if_match.0, etag) {
+                (EntityTagList::Any, etag) => etag.is_some(),
+                (entity_tags, Some(etag)) => entity_tags.matches_strong(etag),
+                (_, None) => false,
+            };
+
+            if !matches {
+                return Err(StatusCode::PreconditionFailed);
+            }
+        } else if let (Some(if_unmodified_since), Some(last_modified)) =
+            (self.typed_header::<IfUnmodifiedSince>(), last_modified)
+        {
+            if last_modified > if_unmodified_since.0 {
+                return Err(StatusCode::PreconditionFailed);
+            }
+        }
+
+        let is_safe = matches!(self.method, Method::GET | Method::HEAD);
+
+        if let Some(if_none_match) = self.typed_header::<IfNoneMatch>() {
+            let matches = match (&if_none_match.0, etag) {
+                (EntityTagList::Any, etag) => etag.is_some(),
+                (entity_tags, Some(etag)) => entity_tags.matches_weak(etag),
+                (_, None) => false,
+            };
+
+            if matches && is_safe {
+                return Err(StatusCode::NotModified);
+            } else if matches {
+                return Err(StatusCode::PreconditionFailed);
+            }
+        } else if let (true, Some(if_modified_since), Some(last_modified)) = (
+            is_safe,
+            self.typed_header::<IfModifiedSince>(),
+            last_modified,
+        ) {
+            if last_modified <= if_modified_since.0 {
+                return Err(StatusCode::NotModified);
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn cookie(&self, key: &str) -> Option<Cookie> {
-        self.cookies.cookie(key).cloned()
+        self.cookies.cookie(key)?.decode(self.cookie_encoding)
+    }
+
+    #[cfg(feature = "signed")]
+    pub fn signed_cookie(&self, key: &str) -> Option<Cookie> {
+        let cookie = self.cookies.cookie(key)?;
+        let value = self
+            .secret_keys
+            .iter()
+            .find_map(|secret_key| secret_key.verify(key, cookie.value()))?;
+
+        cookie.with_value(value).decode(self.cookie_encoding)
+    }
+
+    #[cfg(feature = "private")]
+    pub fn private_cookie(&self, key: &str) -> Option<Cookie> {
+        let cookie = self.cookies.cookie(key)?;
+        let value = self
+            .secret_keys
+            .iter()
+            .find_map(|secret_key| secret_key.decrypt(key, cookie.value()))?;
+
+        Some(cookie.with_value(value))
     }
 
     pub fn body(&self) -> Result<String, SerwerError> {
@@ -128,14 +361,146 @@ impl Request {
         self.body.to_owned()
     }
 
+    #[cfg(feature = "serde")]
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T, SerwerError> {
+        if let Some(content_type) = self.headers.header(ContentType::name()) {
+            let media_type = ContentType::from_value(content_type)?.0;
+
+            if media_type.essence() != "application/json"
+                && !media_type.sub_type().ends_with("+json")
+            {
+                return Err(SerwerError::UnexpectedContentType(content_type.clone()));
+            }
+        }
+
+        serde_json::from_slice(&self.body).map_err(|error| match error.classify() {
+            serde_json::error::Category::Data => SerwerError::DeserializeError(error.to_string()),
+            _ => SerwerError::InvalidJson(error.to_string()),
+        })
+    }
+
+    pub fn form<T: FromForm>(&self) -> Result<T, SerwerError> {
+        if let Some(content_type) = self.headers.header(ContentType::name()) {
+            let media_type = ContentType::from_value(content_type)?.0;
+
+            if media_type.essence() != "application/x-www-form-urlencoded" {
+                return Err(SerwerError::UnexpectedContentType(content_type.clone()));
+            }
+        }
+
+        T::from_form(Form::from_string(&self.body()?)?)
+    }
+
+    pub fn multipart(&self) -> Result<Multipart, SerwerError> {
+        self.multipart_with(&MultipartConfig::new())
+    }
+
+    pub fn multipart_with(&self, config: &MultipartConfig) -> Result<Multipart, SerwerError> {
+        let content_type = self
+            .headers
+            .header(ContentType::name())
+            .ok_or(SerwerError::UnexpectedContentType(String::new()))?;
+        let media_type = ContentType::from_value(content_type)?.0;
+
+        if media_type.essence() != "multipart/form-data" {
+            return Err(SerwerError::UnexpectedContentType(content_type.clone()));
+        }
+
+        let boundary = media_type
+            .param("boundary")
+            .ok_or(SerwerError::InvalidMultipart(String::from(
+                "missing boundary",
+            )))?;
+
+        Multipart::from_bytes(&self.body, boundary, config)
+    }
+
+    pub fn state<T: Send + Sync + 'static>(&self) -> Option<Data<T>> {
+        self.states.state::<T>()
+    }
+
+    pub fn extensions(&self) -> &Extensions {
+        &self.extensions
+    }
+
+    pub fn extensions_mut(&mut self) -> &mut Extensions {
+        &mut self.extensions
+    }
+
     pub fn param(&self, key: &str) -> Option<String> {
         self.params.param(key).cloned()
     }
 
+    #[cfg(feature = "serde")]
+    pub fn params<T: DeserializeOwned>(&self) -> Result<T, SerwerError> {
+        PairsDeserializer::deserialize(self.params.params())
+    }
+
+    pub(crate) fn decompress(&mut self, config: &DecompressionConfig) -> Result<(), SerwerError> {
+        let Some(content_encoding) = self.headers.header_joined("Content-Encoding") else {
+            return Ok(());
+        };
+
+        let encodings = content_encoding
+            .split(',')
+            .map(|encoding| ContentEncoding::from_string(encoding.trim()))
+            .collect::<Result<Vec<ContentEncoding>, SerwerError>>()?;
+
+        for encoding in encodings.iter().rev() {
+            self.body = encoding.decode(&self.body, config.max_size())?;
+        }
+
+        self.headers.remove_header("Content-Encoding");
+        self.headers
+            .set_header("Content-Length", &self.body.len().to_string());
+
+        Ok(())
+    }
+
+    pub(crate) fn rewrite(
+        &mut self,
+        normalization: &NormalizationConfig,
+        rewrites: &[RewriteRule],
+    ) -> Result<(), SerwerError> {
+        let url = self.original_url();
+        let normalized = normalization.normalize(&url);
+
+        if normalized != url {
+            self.path = Path::from_string(&normalized)?;
+        }
+
+        if let Some(url) = rewrites.iter().find_map(|rewrite| rewrite.rewrite(self)) {
+            self.path = Path::from_string(&url)?;
+        }
+
+        Ok(())
+    }
+
     pub(crate) fn path(&self) -> &Path {
         &self.path
     }
 
+    pub(crate) fn cookies(&self) -> Cookies {
+        self.cookies.decode(self.cookie_encoding)
+    }
+
+    pub(crate) fn states(&self) -> &States {
+        &self.states
+    }
+
+    pub(crate) fn set_states(&mut self, states: States) {
+        self.states = states;
+    }
+
+    pub(crate) fn set_cookie_encoding(&mut self, cookie_encoding: CookieEncoding) {
+        self.cookie_encoding = cookie_encoding;
+    }
+
+    #[cfg(any(feature = "signed", feature = "private"))]
+    pub(crate) fn set_secret_keys(&mut self, secret_keys: Vec<SecretKey>) {
+        self.secret_keys = secret_keys;
+    }
+
     pub(crate) fn set_params(&mut self, params: Params) {
         self.params = params;
     }
@@ -144,7 +509,13 @@ impl Request {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::request_from_bytes;
+    use crate::{
+        request_from_bytes, stream_from_bytes, Accept, Authorization, ContentType, Credentials,
+        IfNoneMatch, MediaType, QualityItem, StatusCode, TrailingSlash,
+    };
+    #[cfg(feature = "serde")]
+    use crate::{FromRequest, SerdeForm};
+    use std::collections::HashMap;
 
     #[test]
     fn test_from_stream() {
@@ -156,6 +527,17 @@ mod tests {
         assert_eq!(result.body().unwrap(), String::from(""));
     }
 
+    #[test]
+    fn test_from_stream_body_too_large() {
+        let stream = stream_from_bytes(b"POST / HTTP/1.1\r\nContent-Length: 5\r\n\r\nHello");
+        let result = Request::from_stream(&stream, 4);
+
+        assert!(matches!(
+            result,
+            Err(SerwerError::BodyTooLarge(error_string)) if &error_string == "4"
+        ));
+    }
+
     #[test]
     fn test_from_stream_http_0_9() {
         let result = request_from_bytes("GET /\r\n\r\n".as_bytes()).unwrap();
@@ -184,6 +566,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_from_stream_repeated_headers() {
+        let result = request_from_bytes(
+            "GET / HTTP/1.1\r\nAccept: text/html\r\nX-Forwarded-For: 10.0.0.1\r\nAccept: application/json\r\nX-Forwarded-For: 10.0.0.2\r\n\r\n".as_bytes(),
+        )
+        .unwrap();
+
+        assert_eq!(result.header("Accept"), Some(String::from("text/html")));
+        assert_eq!(
+            result.header_all("x-forwarded-for"),
+            vec![String::from("10.0.0.1"), String::from("10.0.0.2")]
+        );
+        assert_eq!(
+            result.header_joined("Accept"),
+            Some(String::from("text/html, application/json"))
+        );
+        assert_eq!(result.header_all("Host"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_from_stream_typed_headers() {
+        let result = request_from_bytes(
+            "GET / HTTP/1.1\r\nAccept: text/html\r\nAccept: */*;q=0.1\r\nAuthorization: Bearer abc\r\nIf-None-Match: invalid\r\n\r\n".as_bytes(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            result.typed_header::<Accept>(),
+            Some(Accept(vec![
+                QualityItem::new(MediaType::new("text", "html"), 1000),
+                QualityItem::new(MediaType::new("*", "*"), 100),
+            ]))
+        );
+        assert_eq!(
+            result.typed_header::<Authorization>(),
+            Some(Authorization(Credentials::Bearer(String::from("abc"))))
+        );
+        assert_eq!(result.typed_header::<IfNoneMatch>(), None);
+        assert_eq!(result.typed_header::<ContentType>(), None);
+    }
+
     #[test]
     fn test_from_stream_cookies() {
         let result =
@@ -202,6 +625,20 @@ mod tests {
             result.cookie("name"),
             Some(Cookie::from_string("name=John").unwrap())
         );
+
+        let result = request_from_bytes(
+            "GET / HTTP/1.1\r\nCookie: id=1\r\nCookie: name=John\r\n\r\n".as_bytes(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            result.cookie("id"),
+            Some(Cookie::from_string("id=1").unwrap())
+        );
+        assert_eq!(
+            result.cookie("name"),
+            Some(Cookie::from_string("name=John").unwrap())
+        );
     }
 
     #[test]
@@ -221,6 +658,180 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_from_stream_form() {
+        let result = request_from_bytes(
+            "POST / HTTP/1.1\r\nContent-Type: application/x-www-form-urlencoded\r\nContent-Length: 28\r\n\r\nname=John+Doe&tag=a&tag=&x=1".as_bytes(),
+        )
+        .unwrap();
+
+        let form = result.form::<Form>().unwrap();
+        assert_eq!(form.field("name"), Some(&String::from("John Doe")));
+        assert_eq!(
+            form.fields_all("tag"),
+            vec![&String::from("a"), &String::from("")]
+        );
+
+        let map = result.form::<HashMap<String, String>>().unwrap();
+        assert_eq!(map.get("x"), Some(&String::from("1")));
+    }
+
+    #[test]
+    fn test_from_stream_form_unexpected_content_type() {
+        let result = request_from_bytes(
+            "POST / HTTP/1.1\r\nContent-Type: application/json\r\nContent-Length: 2\r\n\r\n{}"
+                .as_bytes(),
+        )
+        .unwrap();
+
+        assert!(matches!(
+            result.form::<Form>(),
+            Err(SerwerError::UnexpectedContentType(error_string)) if &error_string == "application/json"
+        ));
+    }
+
+    #[test]
+    fn test_from_stream_multipart() {
+        let body = "--b\r\nContent-Disposition: form-data; name=\"avatar\"; filename=\"me.png\"\r\nContent-Type: image/png\r\n\r\nPNG\r\n--b--\r\n";
+        let request = format!(
+            "POST / HTTP/1.1\r\nContent-Type: multipart/form-data; boundary=b\r\nContent-Length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        let result = request_from_bytes(request.as_bytes()).unwrap();
+
+        let multipart = result.multipart().unwrap();
+        let avatar = multipart.part("avatar").unwrap();
+        assert_eq!(avatar.filename(), Some(&String::from("me.png")));
+        assert_eq!(avatar.data(), "PNG".as_bytes());
+
+        let config = MultipartConfig::new().set_max_part_size(2);
+        assert!(matches!(
+            result.multipart_with(&config),
+            Err(SerwerError::PartTooLarge(error_string)) if &error_string == "avatar"
+        ));
+    }
+
+    #[test]
+    fn test_from_stream_multipart_invalid_content_type() {
+        let result = request_from_bytes(
+            "POST / HTTP/1.1\r\nContent-Type: multipart/form-data\r\n\r\n".as_bytes(),
+        )
+        .unwrap();
+        assert!(matches!(
+            result.multipart(),
+            Err(SerwerError::InvalidMultipart(error_string)) if &error_string == "missing boundary"
+        ));
+
+        let result = request_from_bytes("POST / HTTP/1.1\r\n\r\n".as_bytes()).unwrap();
+        assert!(matches!(
+            result.multipart(),
+            Err(SerwerError::UnexpectedContentType(error_string)) if error_string.is_empty()
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    struct Task {
+        id: u32,
+        description: String,
+        completed: Option<bool>,
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_from_stream_json() {
+        let body = r#"{"id":1,"description":"Write tests"}"#;
+        let request = format!(
+            "POST / HTTP/1.1\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        let result = request_from_bytes(request.as_bytes()).unwrap();
+
+        assert_eq!(
+            result.json::<Task>().unwrap(),
+            Task {
+                id: 1,
+                description: String::from("Write tests"),
+                completed: None,
+            }
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_from_stream_json_invalid() {
+        let result = request_from_bytes(
+            "POST / HTTP/1.1\r\nContent-Type: application/json\r\nContent-Length: 8\r\n\r\n{\"id\":1}"
+                .as_bytes(),
+        )
+        .unwrap();
+        let error = result.json::<Task>().unwrap_err();
+        assert_eq!(error.status_code(), StatusCode::UnprocessableEntity);
+        assert_eq!(
+            error.to_string(),
+            "Deserialize error: missing field `description` at line 1 column 8"
+        );
+
+        let result =
+            request_from_bytes("POST / HTTP/1.1\r\nContent-Length: 6\r\n\r\n{\"id\":".as_bytes())
+                .unwrap();
+        let error = result.json::<Task>().unwrap_err();
+        assert_eq!(error.status_code(), StatusCode::BadRequest);
+        assert!(matches!(error, SerwerError::InvalidJson(_)));
+
+        let result = request_from_bytes(
+            "POST / HTTP/1.1\r\nContent-Type: text/plain\r\nContent-Length: 2\r\n\r\n{}".as_bytes(),
+        )
+        .unwrap();
+        assert!(matches!(
+            result.json::<Task>(),
+            Err(SerwerError::UnexpectedContentType(error_string)) if &error_string == "text/plain"
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_from_stream_query_form_and_params() {
+        let mut result = request_from_bytes(
+            "POST /?id=1&description=Write+tests&completed=true HTTP/1.1\r\nContent-Type: application/x-www-form-urlencoded\r\nContent-Length: 30\r\n\r\nid=2&description=Run%20the+app"
+                .as_bytes(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            result.query::<Task>().unwrap(),
+            Task {
+                id: 1,
+                description: String::from("Write tests"),
+                completed: Some(true),
+            }
+        );
+        assert_eq!(
+            SerdeForm::<Task>::from_request(&result).unwrap().0,
+            Task {
+                id: 2,
+                description: String::from("Run the app"),
+                completed: None,
+            }
+        );
+
+        let mut params = Params::new();
+        params.set_param("id", "3");
+        params.set_param("description", "Deploy");
+        result.set_params(params);
+
+        assert_eq!(
+            result.params::<Task>().unwrap(),
+            Task {
+                id: 3,
+                description: String::from("Deploy"),
+                completed: None,
+            }
+        );
+    }
+
     #[test]
     fn test_from_stream_query_params() {
         let result =
@@ -232,6 +843,22 @@ mod tests {
         assert_eq!(result.body().unwrap(), String::from(""));
         assert_eq!(result.query_param("id"), Some(String::from("1")));
         assert_eq!(result.query_param("name"), Some(String::from("John")));
+
+        let result = request_from_bytes(
+            "GET /?tag=a&tag=b&ids[]=1&ids[]=2&flag HTTP/1.1\r\n\r\n".as_bytes(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            result.query_params_all("tag"),
+            vec![String::from("a"), String::from("b")]
+        );
+        assert_eq!(
+            result.query_params_all("ids"),
+            vec![String::from("1"), String::from("2")]
+        );
+        assert_eq!(result.query_param("flag"), Some(String::new()));
+        assert_eq!(result.query_params_all("missing"), Vec::<String>::new());
     }
 
     #[test]
@@ -251,6 +878,133 @@ mod tests {
         assert_eq!(result.param("user").unwrap(), String::from("1"));
     }
 
+    #[test]
+    fn test_accepts() {
+        let result = request_from_bytes(
+            "GET / HTTP/1.1\r\nAccept: text/*;q=0.5, application/json, image/png;q=0\r\n\r\n"
+                .as_bytes(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            result.accepts(&["text/html", "application/json"]),
+            Ok(String::from("application/json"))
+        );
+        assert_eq!(
+            result.accepts(&["text/html", "text/plain"]),
+            Ok(String::from("text/html"))
+        );
+        assert_eq!(
+            result.accepts(&["image/png", "application/xml"]),
+            Err(StatusCode::NotAcceptable)
+        );
+
+        let result = request_from_bytes("GET / HTTP/1.1\r\n\r\n".as_bytes()).unwrap();
+
+        assert_eq!(
+            result.accepts(&["text/html", "application/json"]),
+            Ok(String::from("text/html"))
+        );
+        assert_eq!(result.accepts(&[]), Err(StatusCode::NotAcceptable));
+    }
+
+    #[test]
+    fn test_accepts_language() {
+        let result = request_from_bytes(
+            "GET / HTTP/1.1\r\nAccept-Language: fr-CH, fr;q=0.9, en;q=0.8, *;q=0.5\r\n\r\n"
+                .as_bytes(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            result.accepts_language(&["en-US", "fr-FR", "de"]),
+            Ok(String::from("fr-FR"))
+        );
+        assert_eq!(
+            result.accepts_language(&["de", "EN"]),
+            Ok(String::from("EN"))
+        );
+        assert_eq!(result.accepts_language(&["de"]), Ok(String::from("de")));
+
+        let result =
+            request_from_bytes("GET / HTTP/1.1\r\nAccept-Language: en\r\n\r\n".as_bytes()).unwrap();
+
+        assert_eq!(
+            result.accepts_language(&["english", "de"]),
+            Err(StatusCode::NotAcceptable)
+        );
+    }
+
+    #[test]
+    fn test_accepts_encoding() {
+        let result = request_from_bytes(
+            "GET / HTTP/1.1\r\nAccept-Encoding: br;q=0.5, gzip\r\n\r\n".as_bytes(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            result.accepts_encoding(&["br", "gzip", "identity"]),
+            Ok(String::from("gzip"))
+        );
+        assert_eq!(
+            result.accepts_encoding(&["deflate", "identity"]),
+            Ok(String::from("identity"))
+        );
+
+        let result =
+            request_from_bytes("GET / HTTP/1.1\r\nAccept-Encoding: gzip, *;q=0\r\n\r\n".as_bytes())
+                .unwrap();
+
+        assert_eq!(
+            result.accepts_encoding(&["identity", "br"]),
+            Err(StatusCode::NotAcceptable)
+        );
+    }
+
+    #[test]
+    fn test_accepts_charset() {
+        let result = request_from_bytes(
+            "GET / HTTP/1.1\r\nAccept-Charset: iso-8859-1;q=0.5, UTF-8\r\n\r\n".as_bytes(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            result.accepts_charset(&["iso-8859-1", "utf-8"]),
+            Ok(String::from("utf-8"))
+        );
+        assert_eq!(
+            result.accepts_charset(&["utf-16"]),
+            Err(StatusCode::NotAcceptable)
+        );
+    }
+
+    #[test]
+    fn test_extensions() {
+        let mut result = request_from_bytes("GET / HTTP/1.1\r\n\r\n".as_bytes()).unwrap();
+
+        assert!(result.extensions().is_empty());
+
+        result.extensions_mut().insert(String::from("request-id"));
+
+        assert_eq!(
+            result.clone().extensions().get::<String>(),
+            Some(&String::from("request-id"))
+        );
+    }
+
+    #[test]
+    fn test_state() {
+        let mut result = request_from_bytes("GET / HTTP/1.1\r\n\r\n".as_bytes()).unwrap();
+
+        assert!(result.state::<u32>().is_none());
+
+        let mut states = States::new();
+        states.set_state(Data::new(42u32));
+        result.set_states(states);
+
+        assert_eq!(*result.state::<u32>().unwrap().read(), 42);
+    }
+
     #[test]
     fn test_from_stream_invalid_request_line() {
         let result = request_from_bytes("GET / HTTP/1.1".as_bytes());
@@ -287,4 +1041,223 @@ mod tests {
 
         assert!(matches!(result.body(), Err(SerwerError::FromUtf8Error(_))));
     }
+
+    #[test]
+    fn test_decompress() {
+        let config = DecompressionConfig::new();
+
+        let mut request = request_from_bytes(
+            b"POST / HTTP/1.1\r\nContent-Encoding: identity\r\nContent-Length: 5\r\n\r\nHello",
+        )
+        .unwrap();
+        request.decompress(&config).unwrap();
+        assert_eq!(request.body().unwrap(), "Hello");
+        assert_eq!(request.header("Content-Encoding"), None);
+
+        let mut request =
+            request_from_bytes(b"POST / HTTP/1.1\r\nContent-Length: 5\r\n\r\nHello").unwrap();
+        request.decompress(&config).unwrap();
+        assert_eq!(request.body().unwrap(), "Hello");
+
+        let mut request = request_from_bytes(
+            b"POST / HTTP/1.1\r\nContent-Encoding: compress\r\nContent-Length: 5\r\n\r\nHello",
+        )
+        .unwrap();
+        assert!(matches!(
+            request.decompress(&config),
+            Err(SerwerError::UnsupportedEncoding(error_string)) if &error_string == "compress"
+        ));
+    }
+
+    #[cfg(all(feature = "gzip", feature = "deflate"))]
+    #[test]
+    fn test_decompress_multiple_encodings() {
+        let body = ContentEncoding::Deflate.encode(b"Hello World").unwrap();
+        let body = ContentEncoding::Gzip.encode(&body).unwrap();
+        let mut bytes = format!(
+            "POST / HTTP/1.1\r\nContent-Encoding: deflate, gzip\r\nContent-Length: {}\r\n\r\n",
+            body.len()
+        )
+        .into_bytes();
+        bytes.extend(&body);
+
+        let mut request = request_from_bytes(&bytes).unwrap();
+        request.decompress(&DecompressionConfig::new()).unwrap();
+
+        assert_eq!(request.body().unwrap(), "Hello World");
+        assert_eq!(request.header("Content-Length"), Some(String::from("11")));
+    }
+
+    #[test]
+    fn test_preconditions_if_match() {
+        let current = EntityTag::strong("2");
+
+        let request =
+            request_from_bytes(b"PUT / HTTP/1.1\r\nIf-Match: \"1\", \"2\"\r\n\r\n").unwrap();
+        assert_eq!(request.preconditions(Some(&current), None), Ok(()));
+        assert_eq!(
+            request.preconditions(Some(&EntityTag::strong("3")), None),
+            Err(StatusCode::PreconditionFailed)
+        );
+        assert_eq!(
+            request.preconditions(Some(&EntityTag::weak("2")), None),
+            Err(StatusCode::PreconditionFailed)
+        );
+
+        let request = request_from_bytes(b"PUT / HTTP/1.1\r\nIf-Match: *\r\n\r\n").unwrap();
+        assert_eq!(request.preconditions(Some(&current), None), Ok(()));
+        assert_eq!(
+            request.preconditions(None, None),
+            Err(StatusCode::PreconditionFailed)
+        );
+
+        let request = request_from_bytes(b"PUT / HTTP/1.1\r\n\r\n").unwrap();
+        assert_eq!(request.preconditions(Some(&current), None), Ok(()));
+    }
+
+    #[test]
+    fn test_preconditions_if_none_match() {
+        let current = EntityTag::strong("1");
+
+        let request =
+            request_from_bytes(b"GET / HTTP/1.1\r\nIf-None-Match: W/\"1\"\r\n\r\n").unwrap();
+        assert_eq!(
+            request.preconditions(Some(&current), None),
+            Err(StatusCode::NotModified)
+        );
+        assert_eq!(
+            request.preconditions(Some(&EntityTag::strong("2")), None),
+            Ok(())
+        );
+
+        let request = request_from_bytes(b"PUT / HTTP/1.1\r\nIf-None-Match: *\r\n\r\n").unwrap();
+        assert_eq!(
+            request.preconditions(Some(&current), None),
+            Err(StatusCode::PreconditionFailed)
+        );
+        assert_eq!(request.preconditions(None, None), Ok(()));
+    }
+
+    #[test]
+    fn test_preconditions_dates() {
+        let date = HttpDate::from_unix_timestamp(784111777);
+        let later = HttpDate::from_unix_timestamp(784111778);
+
+        let request = request_from_bytes(
+            b"GET / HTTP/1.1\r\nIf-Modified-Since: Sun, 06 Nov 1994 08:49:37 GMT\r\n\r\n",
+        )
+        .unwrap();
+        assert_eq!(
+            request.preconditions(None, Some(date)),
+            Err(StatusCode::NotModified)
+        );
+        assert_eq!(request.preconditions(None, Some(later)), Ok(()));
+
+        let request = request_from_bytes(
+            b"GET / HTTP/1.1\r\nIf-Modified-Since: Sun, 06 Nov 1994 08:49:37 GMT\r\nIf-None-Match: \"2\"\r\n\r\n",
+        )
+        .unwrap();
+        assert_eq!(
+            request.preconditions(Some(&EntityTag::strong("1")), Some(date)),
+            Ok(())
+        );
+
+        let request = request_from_bytes(
+            b"PUT / HTTP/1.1\r\nIf-Unmodified-Since: Sun, 06 Nov 1994 08:49:37 GMT\r\n\r\n",
+        )
+        .unwrap();
+        assert_eq!(request.preconditions(None, Some(date)), Ok(()));
+        assert_eq!(
+            request.preconditions(None, Some(later)),
+            Err(StatusCode::PreconditionFailed)
+        );
+    }
+
+    #[test]
+    fn test_rewrite() {
+        let rewrites = vec![RewriteRule::new("/u/<id>", "/user/<id>").unwrap()];
+
+        let mut request = request_from_bytes(b"GET //u/./1/?tab=1 HTTP/1.1\r\n\r\n").unwrap();
+        request
+            .rewrite(
+                &NormalizationConfig::new().set_trailing_slash(Some(TrailingSlash::Remove)),
+                &rewrites,
+            )
+            .unwrap();
+
+        assert_eq!(request.original_url(), "/user/1?tab=1");
+        assert_eq!(request.query_param("tab"), Some(String::from("1")));
+
+        let mut request = request_from_bytes(b"GET /a//b HTTP/1.1\r\n\r\n").unwrap();
+        request.rewrite(&NormalizationConfig::new(), &[]).unwrap();
+
+        assert_eq!(request.path(), &Path::from_string("/a/b").unwrap());
+    }
+
+    #[test]
+    fn test_cookie_encoding() {
+        let mut request =
+            request_from_bytes(b"GET / HTTP/1.1\r\nCookie: prefs=a%2C%20b; id=1\r\n\r\n").unwrap();
+
+        assert_eq!(request.cookie("prefs").unwrap().value(), "a%2C%20b");
+
+        request.set_cookie_encoding(CookieEncoding::Percent);
+
+        assert_eq!(request.cookie("prefs").unwrap().value(), "a, b");
+        assert_eq!(request.cookie("id").unwrap().value(), "1");
+        assert_eq!(request.cookies().cookie("prefs").unwrap().value(), "a, b");
+    }
+
+    #[cfg(feature = "signed")]
+    #[test]
+    fn test_signed_cookie() {
+        let old_key = SecretKey::from_secret(&[1; 32]).unwrap();
+        let new_key = SecretKey::from_secret(&[2; 32]).unwrap();
+        let old = old_key.sign("user", "J%C3%B3zef");
+        let new = new_key.sign("theme", "dark");
+
+        let mut request = request_from_bytes(
+            format!(
+                "GET / HTTP/1.1\r\nCookie: user={}; theme={}; id=1\r\n\r\n",
+                old, new
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+        request.set_cookie_encoding(CookieEncoding::Percent);
+
+        assert_eq!(request.signed_cookie("user"), None);
+
+        request.set_secret_keys(vec![new_key, old_key]);
+
+        assert_eq!(request.signed_cookie("user").unwrap().value(), "Józef");
+        assert_eq!(request.signed_cookie("theme").unwrap().value(), "dark");
+        assert_eq!(request.signed_cookie("id"), None);
+        assert_eq!(request.signed_cookie("missing"), None);
+    }
+
+    #[cfg(feature = "private")]
+    #[test]
+    fn test_private_cookie() {
+        let old_key = SecretKey::from_secret(&[1; 32]).unwrap();
+        let new_key = SecretKey::from_secret(&[2; 32]).unwrap();
+        let old = old_key.encrypt("user", "Józef");
+
+        let mut request = request_from_bytes(
+            format!(
+                "GET / HTTP/1.1\r\nCookie: user={}; other={}; id=1\r\n\r\n",
+                old, old
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+
+        assert_eq!(request.private_cookie("user"), None);
+
+        request.set_secret_keys(vec![new_key, old_key]);
+
+        assert_eq!(request.private_cookie("user").unwrap().value(), "Józef");
+        assert_eq!(request.private_cookie("other"), None);
+        assert_eq!(request.private_cookie("id"), None);
+    }
 }
@@ -1,12 +1,35 @@
-use crate::{Cookie, Cookies, Headers, StatusCode, Version};
+#[cfg(any(feature = "signed", feature = "private"))]
+use crate::SecretKey;
+use crate::{
+    encode_uri, fnv1a, Body, CacheControl, CompressionConfig, ContentEncoding, ContentType, Cookie,
+    CookieEncoding, Cookies, ETag, ETagMode, EntityTag, Expires, Extensions, Headers, HttpDate,
+    IfRange, LastModified, MediaType, Range, RangeCondition, Request, SerwerError, StatusCode,
+    TypedHeader, Version,
+};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use std::{
+    collections::hash_map::RandomState,
+    fs,
+    hash::{BuildHasher, Hasher},
+    io::{self, ErrorKind, Write},
+    path::Path,
+    time::Duration,
+};
 
 #[derive(Debug, Clone)]
 pub struct Response {
     version: Version,
     status_code: StatusCode,
-    body: Vec<u8>,
+    body: Body,
     headers: Headers,
     cookies: Cookies,
+    #[cfg(feature = "signed")]
+    signed_cookies: Cookies,
+    #[cfg(feature = "private")]
+    private_cookies: Cookies,
+    preserve_header_case: bool,
+    extensions: Extensions,
 }
 
 impl Response {
@@ -14,9 +37,15 @@ impl Response {
         Self {
             version: version.clone(),
             status_code: StatusCode::OK,
-            body: vec![],
+            body: Body::default(),
             headers: Headers::new(),
             cookies: Cookies::new(),
+            #[cfg(feature = "signed")]
+            signed_cookies: Cookies::new(),
+            #[cfg(feature = "private")]
+            private_cookies: Cookies::new(),
+            preserve_header_case: false,
+            extensions: Extensions::new(),
         }
     }
 
@@ -26,55 +55,540 @@ impl Response {
         self
     }
 
+    pub fn set_error(&mut self, error: &SerwerError) -> &mut Self {
+        let status_code = error.status_code();
+
+        if status_code == StatusCode::InternalServerError {
+            println!("\nInternal server error\n{}\n", error);
+
+            return self.set(status_code, String::from("Internal Server Error"));
+        }
+
+        self.set(status_code, error.to_string())
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn json<T: Serialize>(&mut self, value: &T) -> &mut Self {
+        match serde_json::to_vec(value) {
+            Ok(body) => {
+                self.set_header("Content-Type", "application/json");
+                self.set_body_from_bytes(body)
+            }
+            Err(error) => self.set(StatusCode::InternalServerError, error.to_string()),
+        }
+    }
+
     pub fn set_status_code(&mut self, status_code: StatusCode) -> &mut Self {
         self.status_code = status_code;
         self
     }
 
+    pub fn status_code(&self) -> StatusCode {
+        self.status_code.clone()
+    }
+
+    pub fn header(&self, key: &str) -> Option<String> {
+        self.headers.header(key).cloned()
+    }
+
+    pub fn header_all(&self, key: &str) -> Vec<String> {
+        self.headers.header_all(key).into_iter().cloned().collect()
+    }
+
+    pub fn header_joined(&self, key: &str) -> Option<String> {
+        self.headers.header_joined(key)
+    }
+
     pub fn set_header(&mut self, name: &str, value: &str) -> &mut Self {
         self.headers.set_header(name, value);
         self
     }
 
+    pub fn set_typed_header<T: TypedHeader>(&mut self, header: T) -> &mut Self {
+        self.headers.set_header(T::name(), &header.to_value());
+        self
+    }
+
+    pub fn append_header(&mut self, name: &str, value: &str) -> &mut Self {
+        self.headers.append(name, value);
+        self
+    }
+
+    pub fn remove_header(&mut self, name: &str) -> &mut Self {
+        self.headers.remove_header(name);
+        self
+    }
+
+    pub fn set_max_age(&mut self, max_age: Duration) -> &mut Self {
+        let cache_control = self
+            .headers
+            .header(CacheControl::name())
+            .and_then(|value| CacheControl::from_value(value).ok())
+            .unwrap_or_default();
+
+        self.set_typed_header(cache_control.set_max_age(max_age));
+        self.set_typed_header(Expires(HttpDate::now().add(max_age)))
+    }
+
+    pub fn redirect(&mut self, status_code: StatusCode, location: &str) -> &mut Self {
+        if !status_code.is_redirect()
+            || location.trim().is_empty()
+            || location.chars().any(char::is_control)
+        {
+            return self.set_error(&SerwerError::InvalidRedirect(String::from(location)));
+        }
+
+        self.status_code = status_code;
+        self.set_header("Location", &encode_uri(location))
+    }
+
+    pub fn moved_permanently(&mut self, location: &str) -> &mut Self {
+        self.redirect(StatusCode::MovedPermanently, location)
+    }
+
+    pub fn found(&mut self, location: &str) -> &mut Self {
+        self.redirect(StatusCode::Found, location)
+    }
+
+    pub fn see_other(&mut self, location: &str) -> &mut Self {
+        self.redirect(StatusCode::SeeOther, location)
+    }
+
+    pub fn temporary_redirect(&mut self, location: &str) -> &mut Self {
+        self.redirect(StatusCode::TemporaryRedirect, location)
+    }
+
+    pub fn permanent_redirect(&mut self, location: &str) -> &mut Self {
+        self.redirect(StatusCode::PermanentRedirect, location)
+    }
+
     pub fn set_cookie(&mut self, name: &str, cookie: Cookie) -> &mut Self {
+        if let Err(error) = cookie.validate() {
+            return self.set_error(&error);
+        }
+
         self.cookies.set_cookie(name, cookie);
         self
     }
 
+    pub fn remove_cookie(&mut self, name: &str) -> &mut Self {
+        self.remove_cookie_with(name, None, Some("/"))
+    }
+
+    pub fn remove_cookie_with(
+        &mut self,
+        name: &str,
+        domain: Option<&str>,
+        path: Option<&str>,
+    ) -> &mut Self {
+        let mut removal = Cookie::new(name, "")
+            .set_expires(HttpDate::from_unix_timestamp(0))
+            .set_max_age(Duration::ZERO);
+
+        if let Some(domain) = domain {
+            removal = removal.set_domain(domain);
+        }
+
+        if let Some(path) = path {
+            removal = removal.set_path(path);
+        }
+
+        self.set_cookie(name, removal)
+    }
+
+    #[cfg(feature = "signed")]
+    pub fn set_signed_cookie(&mut self, name: &str, cookie: Cookie) -> &mut Self {
+        if let Err(error) = cookie.validate() {
+            return self.set_error(&error);
+        }
+
+        self.signed_cookies.set_cookie(name, cookie);
+        self
+    }
+
+    #[cfg(feature = "private")]
+    pub fn set_private_cookie(&mut self, name: &str, cookie: Cookie) -> &mut Self {
+        if let Err(error) = cookie.validate() {
+            return self.set_error(&error);
+        }
+
+        self.private_cookies.set_cookie(name, cookie);
+        self
+    }
+
+    pub fn set_preserve_header_case(&mut self, preserve_header_case: bool) -> &mut Self {
+        self.preserve_header_case = preserve_header_case;
+        self
+    }
+
+    pub fn extensions(&self) -> &Extensions {
+        &self.extensions
+    }
+
+    pub fn extensions_mut(&mut self) -> &mut Extensions {
+        &mut self.extensions
+    }
+
     pub fn set_body(&mut self, body: &str) -> &mut Self {
         self.set_header("Content-Length", body.len().to_string().as_str());
-        self.body = body.as_bytes().to_vec();
+        self.body = Body::Bytes(body.as_bytes().to_vec());
         self
     }
 
     pub fn set_body_from_bytes(&mut self, body: Vec<u8>) -> &mut Self {
         self.set_header("Content-Length", body.len().to_string().as_str());
-        self.body = body;
+        self.body = Body::Bytes(body);
+        self
+    }
+
+    pub fn file<P: AsRef<Path>>(&mut self, path: P) -> &mut Self {
+        let path = path.as_ref();
+
+        let metadata = match fs::metadata(path) {
+            Ok(metadata) if metadata.is_file() => metadata,
+            Ok(_) => {
+                self.set_status_code(StatusCode::NotFound);
+                return self;
+            }
+            Err(error) if error.kind() == ErrorKind::NotFound => {
+                self.set_status_code(StatusCode::NotFound);
+                return self;
+            }
+            Err(error) => return self.set_error(&SerwerError::from(error)),
+        };
+
+        if self.headers.header(ContentType::name()).is_none() {
+            let media_type = path
+                .extension()
+                .map(|extension| MediaType::from_extension(&extension.to_string_lossy()))
+                .unwrap_or(MediaType::new("application", "octet-stream"));
+
+            self.set_typed_header(ContentType(media_type));
+        }
+
+        if let Ok(modified) = metadata.modified() {
+            self.set_typed_header(LastModified(HttpDate::from_system_time(modified)));
+        }
+
+        self.set_header("Accept-Ranges", "bytes");
+        self.set_header("Content-Length", &metadata.len().to_string());
+        self.body = Body::File {
+            path: path.to_path_buf(),
+            offset: 0,
+            length: metadata.len(),
+        };
         self
     }
 
+    pub fn body(&self) -> &Body {
+        &self.body
+    }
+
+    pub(crate) fn clear_body(&mut self) {
+        self.body = Body::default();
+    }
+
+    pub(crate) fn set_default_cookie_encoding(&mut self, cookie_encoding: CookieEncoding) {
+        self.cookies.set_default_encoding(cookie_encoding);
+        #[cfg(feature = "signed")]
+        self.signed_cookies.set_default_encoding(cookie_encoding);
+    }
+
+    #[cfg(any(feature = "signed", feature = "private"))]
+    pub(crate) fn seal_cookies(&mut self, secret_keys: &[SecretKey]) {
+        if let Err(error) = self.try_seal_cookies(secret_keys) {
+            self.set_error(&error);
+        }
+    }
+
+    #[cfg(any(feature = "signed", feature = "private"))]
+    fn try_seal_cookies(&mut self, secret_keys: &[SecretKey]) -> Result<(), SerwerError> {
+        #[cfg(feature = "signed")]
+        self.cookies
+            .extend(std::mem::take(&mut self.signed_cookies).sign(secret_keys)?);
+        #[cfg(feature = "private")]
+        self.cookies
+            .extend(std::mem::take(&mut self.private_cookies).encrypt(secret_keys)?);
+
+        Ok(())
+    }
+
+    pub(crate) fn has_cookies(&self) -> bool {
+        !self.cookies.is_empty()
+    }
+
+    pub(crate) fn set_default_cache_control(&mut self, cache_control: &CacheControl) {
+        let status_code = self.status_code.clone() as u16;
+
+        if ((200..300).contains(&status_code) || self.status_code == StatusCode::NotModified)
+            && self.headers.header(CacheControl::name()).is_none()
+        {
+            self.set_typed_header(cache_control.clone());
+        }
+    }
+
+    pub(crate) fn generate_etag(&mut self, mode: ETagMode) {
+        if self.status_code != StatusCode::OK || self.headers.header(ETag::name()).is_some() {
+            return;
+        }
+
+        let Body::Bytes(bytes) = &self.body else {
+            return;
+        };
+
+        let tag = format!("{:x}-{:016x}", bytes.len(), fnv1a(bytes));
+
+        self.set_typed_header(ETag(match mode {
+            ETagMode::Strong => EntityTag::strong(&tag),
+            ETagMode::Weak => EntityTag::weak(&tag),
+        }));
+    }
+
+    pub(crate) fn apply_conditionals(&mut self, request: &Request) {
+        if self.status_code != StatusCode::OK {
+            return;
+        }
+
+        let etag = self
+            .headers
+            .header(ETag::name())
+            .and_then(|value| ETag::from_value(value).ok())
+            .map(|etag| etag.0);
+        let last_modified = self
+            .headers
+            .header(LastModified::name())
+            .and_then(|value| LastModified::from_value(value).ok())
+            .map(|last_modified| last_modified.0);
+
+        match request.preconditions(etag.as_ref(), last_modified) {
+            Ok(()) => self.apply_range(request, etag, last_modified),
+            Err(status_code) => self.set_empty(status_code),
+        }
+    }
+
+    fn set_empty(&mut self, status_code: StatusCode) {
+        if status_code == StatusCode::NotModified {
+            self.remove_header("Content-Length");
+        } else {
+            self.set_header("Content-Length", "0");
+        }
+
+        self.status_code = status_code;
+        self.body = Body::default();
+        self.remove_header("Content-Type");
+    }
+
+    fn apply_range(
+        &mut self,
+        request: &Request,
+        etag: Option<EntityTag>,
+        last_modified: Option<HttpDate>,
+    ) {
+        if self
+            .headers
+            .header("Accept-Ranges")
+            .is_none_or(|accept_ranges| accept_ranges != "bytes")
+            || self.headers.header("Content-Encoding").is_some()
+        {
+            return;
+        }
+
+        let Some(range) = request.typed_header::<Range>() else {
+            return;
+        };
+
+        if let Some(if_range) = request.typed_header::<IfRange>() {
+            let matches = match if_range.0 {
+                RangeCondition::EntityTag(entity_tag) => {
+                    etag.is_some_and(|etag| etag.strong_eq(&entity_tag))
+                }
+                RangeCondition::Date(date) => last_modified == Some(date),
+            };
+
+            if !matches {
+                return;
+            }
+        }
+
+        let length = self.body.len();
+        let bounds: Vec<(u64, u64)> = range
+            .0
+            .iter()
+            .filter_map(|byte_range| byte_range.bounds(length))
+            .collect();
+
+        if bounds.is_empty() {
+            self.set_empty(StatusCode::RangeNotSatisfiable);
+            self.set_header("Content-Range", &format!("bytes */{}", length));
+            return;
+        }
+
+        self.status_code = StatusCode::PartialContent;
+
+        if let [(start, end)] = bounds[..] {
+            self.body = self.body.slice(start, end);
+            self.set_header(
+                "Content-Range",
+                &format!("bytes {}-{}/{}", start, end, length),
+            );
+        } else {
+            let boundary = format!("{:016x}", RandomState::new().build_hasher().finish());
+            let content_type = self.headers.header(ContentType::name()).cloned();
+            let mut chunks = vec![];
+
+            for (index, (start, end)) in bounds.into_iter().enumerate() {
+                let mut part = if index == 0 {
+                    format!("--{}\r\n", boundary)
+                } else {
+                    format!("\r\n--{}\r\n", boundary)
+                };
+
+                if let Some(content_type) = &content_type {
+                    part.push_str(&format!("Content-Type: {}\r\n", content_type));
+                }
+
+                part.push_str(&format!(
+                    "Content-Range: bytes {}-{}/{}\r\n\r\n",
+                    start, end, length
+                ));
+
+                chunks.push(Body::Bytes(part.into_bytes()));
+                chunks.push(self.body.slice(start, end));
+            }
+
+            chunks.push(Body::Bytes(
+                format!("\r\n--{}--\r\n", boundary).into_bytes(),
+            ));
+
+            self.body = Body::Chunks(chunks);
+            self.set_typed_header(ContentType(
+                MediaType::new("multipart", "byteranges").set_param("boundary", &boundary),
+            ));
+        }
+
+        self.set_header("Content-Length", &self.body.len().to_string());
+    }
+
+    pub(crate) fn compress(&mut self, request: &Request, config: &CompressionConfig) {
+        if !self.is_compressible(config) {
+            return;
+        }
+
+        let varies_on_encoding = self.headers.header_all("Vary").iter().any(|vary| {
+            vary.split(',').any(|name| {
+                name.trim() == "*" || name.trim().eq_ignore_ascii_case("Accept-Encoding")
+            })
+        });
+
+        if !varies_on_encoding {
+            self.append_header("Vary", "Accept-Encoding");
+        }
+
+        if request.header("Accept-Encoding").is_none() {
+            return;
+        }
+
+        let mut available: Vec<String> = config
+            .encodings()
+            .iter()
+            .map(ContentEncoding::to_string)
+            .collect();
+        available.push(ContentEncoding::Identity.to_string());
+
+        let available: Vec<&str> = available.iter().map(String::as_str).collect();
+
+        let encoding = match request
+            .accepts_encoding(&available)
+            .map(|encoding| ContentEncoding::from_string(&encoding))
+        {
+            Ok(Ok(encoding)) if encoding != ContentEncoding::Identity => encoding,
+            _ => return,
+        };
+
+        let body = match encoding.encoder(vec![]).and_then(|mut encoder| {
+            self.body.write_to(&mut encoder)?;
+            Ok(encoder.finish()?)
+        }) {
+            Ok(body) if (body.len() as u64) < self.body.len() => body,
+            _ => return,
+        };
+
+        self.set_header("Content-Encoding", &encoding.to_string());
+        self.set_body_from_bytes(body);
+
+        if let Some(etag) = self.headers.header("ETag").cloned() {
+            if !etag.starts_with("W/") {
+                self.set_header("ETag", &format!("W/{}", etag));
+            }
+        }
+    }
+
+    fn is_compressible(&self, config: &CompressionConfig) -> bool {
+        let status_code = self.status_code.clone() as u16;
+
+        if status_code < 200
+            || matches!(
+                self.status_code,
+                StatusCode::NoContent | StatusCode::PartialContent | StatusCode::NotModified
+            )
+            || self.body.len() < config.min_size() as u64
+            || (matches!(self.body, Body::File { .. }) && self.body.len() > config.max_file_size())
+            || self.headers.header("Content-Encoding").is_some()
+        {
+            return false;
+        }
+
+        if self
+            .headers
+            .header_joined("Cache-Control")
+            .is_some_and(|cache_control| cache_control.to_lowercase().contains("no-transform"))
+        {
+            return false;
+        }
+
+        self.headers
+            .header("Content-Type")
+            .and_then(|content_type| MediaType::from_string(content_type).ok())
+            .is_some_and(|media_type| config.is_compressible(&media_type))
+    }
+
+    #[cfg(test)]
     pub(crate) fn write(self) -> Vec<u8> {
         let mut response: Vec<u8> = vec![];
 
-        response.extend(self.version.to_string().as_bytes());
-        response.extend(b" ");
-        response.extend(self.status_code.to_string().as_bytes());
-        response.extend(b"\r\n");
+        self.write_to(&mut response).unwrap();
+
+        response
+    }
+
+    pub(crate) fn write_to<W: Write>(self, writer: &mut W) -> io::Result<()> {
+        let mut head: Vec<u8> = vec![];
 
-        response.extend(self.headers.to_bytes());
+        head.extend(self.version.to_string().as_bytes());
+        head.extend(b" ");
+        head.extend(self.status_code.to_string().as_bytes());
+        head.extend(b"\r\n");
 
-        response.extend(self.cookies.to_bytes());
+        if self.preserve_header_case {
+            head.extend(self.headers.to_bytes_preserving_case());
+            head.extend(self.cookies.to_bytes_preserving_case());
+        } else {
+            head.extend(self.headers.to_bytes());
+            head.extend(self.cookies.to_bytes());
+        }
 
-        response.extend(b"\r\n");
-        response.extend(self.body);
+        head.extend(b"\r\n");
 
-        response
+        writer.write_all(&head)?;
+        self.body.write_to(writer)?;
+        writer.flush()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{request_from_bytes, EntityTag, SameSite};
 
     #[test]
     fn test_write() {
@@ -103,6 +617,57 @@ mod tests {
         assert_eq!(result, "HTTP/1.1 200 OK\r\ncontent-type: text/html\r\n\r\n");
     }
 
+    #[test]
+    fn test_write_with_repeated_header() {
+        let mut response = Response::new(&Version::HTTP_1_1);
+        response.append_header("Vary", "Accept");
+        response.append_header("Vary", "Accept-Encoding");
+        assert_eq!(
+            response.header_all("vary"),
+            vec![String::from("Accept"), String::from("Accept-Encoding")]
+        );
+        assert_eq!(
+            response.header_joined("Vary"),
+            Some(String::from("Accept, Accept-Encoding"))
+        );
+        let result = String::from_utf8(response.clone().write()).unwrap();
+        assert_eq!(
+            result,
+            "HTTP/1.1 200 OK\r\nvary: Accept\r\nvary: Accept-Encoding\r\n\r\n"
+        );
+        response.remove_header("vary");
+        let result = String::from_utf8(response.write()).unwrap();
+        assert_eq!(result, "HTTP/1.1 200 OK\r\n\r\n");
+    }
+
+    #[test]
+    fn test_write_with_typed_header() {
+        let mut response = Response::new(&Version::HTTP_1_1);
+        response.set_typed_header(ContentType(
+            MediaType::new("text", "html").set_param("charset", "utf-8"),
+        ));
+        response.set_typed_header(ETag(EntityTag::weak("1")));
+        let result = String::from_utf8(response.write()).unwrap();
+        assert_eq!(
+            result,
+            "HTTP/1.1 200 OK\r\ncontent-type: text/html; charset=utf-8\r\netag: W/\"1\"\r\n\r\n"
+        );
+    }
+
+    #[test]
+    fn test_write_preserving_header_case() {
+        let mut response = Response::new(&Version::HTTP_1_1);
+        response.set_preserve_header_case(true);
+        response.set_header("X-Powered-By", "serwer");
+        response.set_cookie("id", Cookie::new("id", "1"));
+        response.set_body("Hello World");
+        let result = String::from_utf8(response.write()).unwrap();
+        assert_eq!(
+            result,
+            "HTTP/1.1 200 OK\r\nX-Powered-By: serwer\r\nContent-Length: 11\r\nSet-Cookie: id=1\r\n\r\nHello World"
+        );
+    }
+
     #[test]
     fn test_write_with_cookies() {
         let mut response = Response::new(&Version::HTTP_1_1);
@@ -111,6 +676,49 @@ mod tests {
         assert_eq!(result, "HTTP/1.1 200 OK\r\nset-cookie: id=1\r\n\r\n");
     }
 
+    #[test]
+    fn test_remove_cookie() {
+        let mut response = Response::new(&Version::HTTP_1_1);
+        response.set_cookie("id", Cookie::new("id", "1").set_path("/"));
+        response.remove_cookie("id");
+        let result = String::from_utf8(response.write()).unwrap();
+        assert_eq!(
+            result,
+            "HTTP/1.1 200 OK\r\nset-cookie: id=; Expires=Thu, 01 Jan 1970 00:00:00 GMT; Max-Age=0; Path=/\r\n\r\n"
+        );
+    }
+
+    #[test]
+    fn test_remove_cookie_with() {
+        let mut response = Response::new(&Version::HTTP_1_1);
+        response.set_cookie("id", Cookie::new("id", "1").set_path("/"));
+        response.set_cookie(
+            "id",
+            Cookie::new("id", "1")
+                .set_domain("example.com")
+                .set_path("/admin"),
+        );
+        response.remove_cookie_with("id", Some("example.com"), Some("/admin"));
+        let result = String::from_utf8(response.write()).unwrap();
+        assert_eq!(
+            result,
+            "HTTP/1.1 200 OK\r\nset-cookie: id=1; Path=/\r\nset-cookie: id=; Expires=Thu, 01 Jan 1970 00:00:00 GMT; Max-Age=0; Domain=example.com; Path=/admin\r\n\r\n"
+        );
+    }
+
+    #[test]
+    fn test_set_cookie_invalid_attribute() {
+        let mut response = Response::new(&Version::HTTP_1_1);
+        response.set_cookie("id", Cookie::new("id", "1").set_same_site(SameSite::None));
+
+        assert_eq!(response.status_code(), StatusCode::InternalServerError);
+        assert!(!response.has_cookies());
+        assert_eq!(
+            response.body().to_bytes().unwrap(),
+            b"Internal Server Error"
+        );
+    }
+
     #[test]
     fn test_write_with_body() {
         let mut response = Response::new(&Version::HTTP_1_1);
@@ -127,4 +735,514 @@ mod tests {
             "HTTP/1.1 200 OK\r\ncontent-length: 11\r\n\r\nWorld Hello"
         );
     }
+
+    #[test]
+    fn test_extensions() {
+        let mut response = Response::new(&Version::HTTP_1_1);
+        response.extensions_mut().insert(42u32);
+        assert_eq!(response.extensions().get::<u32>(), Some(&42));
+
+        let result = String::from_utf8(response.write()).unwrap();
+        assert_eq!(result, "HTTP/1.1 200 OK\r\n\r\n");
+    }
+
+    #[test]
+    fn test_write_with_error() {
+        let mut response = Response::new(&Version::HTTP_1_1);
+        response.set_error(&SerwerError::DeserializeError(String::from(
+            "missing field `name`",
+        )));
+        let result = String::from_utf8(response.write()).unwrap();
+        assert_eq!(
+            result,
+            "HTTP/1.1 422 Unprocessable Entity\r\ncontent-length: 39\r\n\r\nDeserialize error: missing field `name`"
+        );
+    }
+
+    #[test]
+    fn test_write_with_internal_error() {
+        let mut response = Response::new(&Version::HTTP_1_1);
+        response.set_error(&SerwerError::from(std::io::Error::new(
+            std::io::ErrorKind::PermissionDenied,
+            "/srv/app/secret.key",
+        )));
+        let result = String::from_utf8(response.write()).unwrap();
+        assert_eq!(
+            result,
+            "HTTP/1.1 500 Internal Server Error\r\ncontent-length: 21\r\n\r\nInternal Server Error"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_write_json() {
+        #[derive(Serialize)]
+        struct Task {
+            id: u32,
+            description: &'static str,
+        }
+
+        let mut response = Response::new(&Version::HTTP_1_1);
+        response.json(&Task {
+            id: 1,
+            description: "Write tests",
+        });
+        let result = String::from_utf8(response.write()).unwrap();
+        assert_eq!(
+            result,
+            "HTTP/1.1 200 OK\r\ncontent-length: 36\r\ncontent-type: application/json\r\n\r\n{\"id\":1,\"description\":\"Write tests\"}"
+        );
+    }
+
+    fn compressible_response() -> Response {
+        let mut response = Response::new(&Version::HTTP_1_1);
+        response.set_header("Content-Type", "text/plain");
+        response.set_header("ETag", "\"1\"");
+        response.set_body(&"Hello World ".repeat(100));
+        response
+    }
+
+    #[test]
+    fn test_compress_skipped() {
+        let config = CompressionConfig::new();
+        let request =
+            request_from_bytes(b"GET / HTTP/1.1\r\nAccept-Encoding: gzip, deflate, br\r\n\r\n")
+                .unwrap();
+
+        let mut response = compressible_response();
+        response.set_body("Hello World");
+        response.compress(&request, &config);
+        assert_eq!(response.headers.header("Content-Encoding"), None);
+        assert_eq!(response.headers.header("Vary"), None);
+
+        let mut response = compressible_response();
+        response.set_header("Content-Type", "image/png");
+        response.compress(&request, &config);
+        assert_eq!(response.headers.header("Content-Encoding"), None);
+
+        let mut response = compressible_response();
+        response.set_header("Cache-Control", "no-transform");
+        response.compress(&request, &config);
+        assert_eq!(response.headers.header("Content-Encoding"), None);
+
+        let mut response = compressible_response();
+        response.set_status_code(StatusCode::PartialContent);
+        response.compress(&request, &config);
+        assert_eq!(response.headers.header("Content-Encoding"), None);
+
+        let mut response = compressible_response();
+        let request = request_from_bytes(b"GET / HTTP/1.1\r\n\r\n").unwrap();
+        response.compress(&request, &config);
+        assert_eq!(response.headers.header("Content-Encoding"), None);
+        assert_eq!(
+            response.headers.header("Vary"),
+            Some(&String::from("Accept-Encoding"))
+        );
+        assert_eq!(response.body.len(), 1200);
+    }
+
+    #[test]
+    fn test_compress_identity() {
+        let config = CompressionConfig::new();
+        let request =
+            request_from_bytes(b"GET / HTTP/1.1\r\nAccept-Encoding: identity\r\n\r\n").unwrap();
+
+        let mut response = compressible_response();
+        response.append_header("Vary", "Accept-Encoding");
+        response.compress(&request, &config);
+
+        assert_eq!(response.headers.header("Content-Encoding"), None);
+        assert_eq!(response.headers.header_all("Vary").len(), 1);
+        assert_eq!(
+            response.headers.header("ETag"),
+            Some(&String::from("\"1\""))
+        );
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn test_compress_gzip() {
+        let config = CompressionConfig::new();
+        let request = request_from_bytes(
+            b"GET / HTTP/1.1\r\nAccept-Encoding: gzip;q=1.0, deflate;q=0.5\r\n\r\n",
+        )
+        .unwrap();
+
+        let mut response = compressible_response();
+        response.compress(&request, &config);
+
+        assert_eq!(
+            response.headers.header("Content-Encoding"),
+            Some(&String::from("gzip"))
+        );
+        assert_eq!(
+            response.headers.header("Content-Length"),
+            Some(&response.body.len().to_string())
+        );
+        assert_eq!(
+            response.headers.header("Vary"),
+            Some(&String::from("Accept-Encoding"))
+        );
+        assert_eq!(
+            response.headers.header("ETag"),
+            Some(&String::from("W/\"1\""))
+        );
+
+        let mut decoded = String::new();
+        std::io::Read::read_to_string(
+            &mut flate2::read::GzDecoder::new(&response.body.to_bytes().unwrap()[..]),
+            &mut decoded,
+        )
+        .unwrap();
+        assert_eq!(decoded, "Hello World ".repeat(100));
+    }
+
+    #[cfg(feature = "brotli")]
+    #[test]
+    fn test_compress_brotli() {
+        let config = CompressionConfig::new();
+        let request =
+            request_from_bytes(b"GET / HTTP/1.1\r\nAccept-Encoding: gzip, deflate, br\r\n\r\n")
+                .unwrap();
+
+        let mut response = compressible_response();
+        response.compress(&request, &config);
+
+        assert_eq!(
+            response.headers.header("Content-Encoding"),
+            Some(&String::from("br"))
+        );
+        assert!(response.body.len() < 1200);
+    }
+
+    #[test]
+    fn test_compress_large_file() {
+        let path = std::env::temp_dir().join("serwer_response_compress_large_file.txt");
+        fs::write(&path, "Hello World ".repeat(200)).unwrap();
+        let config = CompressionConfig::new().set_max_file_size(2048);
+        let request =
+            request_from_bytes(b"GET / HTTP/1.1\r\nAccept-Encoding: gzip, deflate, br\r\n\r\n")
+                .unwrap();
+
+        let mut response = Response::new(&Version::HTTP_1_1);
+        response.file(&path);
+        response.compress(&request, &config);
+
+        assert_eq!(response.headers.header("Content-Encoding"), None);
+        assert_eq!(
+            response.headers.header("Content-Length"),
+            Some(&String::from("2400"))
+        );
+        assert!(matches!(response.body, Body::File { length: 2400, .. }));
+    }
+
+    fn file_response(name: &str) -> (Response, HttpDate) {
+        let path = std::env::temp_dir().join(format!("serwer_response_{}.txt", name));
+        fs::write(&path, "Hello World").unwrap();
+        let modified = HttpDate::from_system_time(fs::metadata(&path).unwrap().modified().unwrap());
+
+        let mut response = Response::new(&Version::HTTP_1_1);
+        response.file(&path);
+
+        (response, modified)
+    }
+
+    fn apply_conditionals(mut response: Response, request: &[u8]) -> Response {
+        response.apply_conditionals(&request_from_bytes(request).unwrap());
+        response
+    }
+
+    #[test]
+    fn test_file() {
+        let (response, modified) = file_response("file");
+
+        assert_eq!(
+            response.headers.header("Content-Type"),
+            Some(&String::from("text/plain; charset=utf-8"))
+        );
+        assert_eq!(
+            response.headers.header("Last-Modified"),
+            Some(&modified.to_string())
+        );
+        assert_eq!(
+            response.headers.header("Accept-Ranges"),
+            Some(&String::from("bytes"))
+        );
+        assert_eq!(
+            response.headers.header("Content-Length"),
+            Some(&String::from("11"))
+        );
+        assert!(matches!(response.body(), Body::File { length: 11, .. }));
+        assert!(String::from_utf8(response.write())
+            .unwrap()
+            .ends_with("\r\n\r\nHello World"));
+
+        let mut response = Response::new(&Version::HTTP_1_1);
+        response.file("/serwer/missing/file.txt");
+        assert_eq!(response.status_code, StatusCode::NotFound);
+    }
+
+    #[test]
+    fn test_apply_conditionals_if_modified_since() {
+        let (response, modified) = file_response("if_modified_since");
+
+        let response = apply_conditionals(
+            response,
+            format!("GET / HTTP/1.1\r\nIf-Modified-Since: {}\r\n\r\n", modified).as_bytes(),
+        );
+
+        assert_eq!(response.status_code, StatusCode::NotModified);
+        assert!(response.body().is_empty());
+        assert_eq!(response.headers.header("Content-Length"), None);
+        assert_eq!(
+            response.headers.header("Last-Modified"),
+            Some(&modified.to_string())
+        );
+
+        let (response, modified) = file_response("if_modified_since_old");
+        let earlier = HttpDate::from_unix_timestamp(modified.unix_timestamp() - 60);
+
+        let response = apply_conditionals(
+            response,
+            format!("GET / HTTP/1.1\r\nIf-Modified-Since: {}\r\n\r\n", earlier).as_bytes(),
+        );
+
+        assert_eq!(response.status_code, StatusCode::OK);
+    }
+
+    #[test]
+    fn test_apply_conditionals_range() {
+        let (response, _) = file_response("range");
+
+        let mut response =
+            apply_conditionals(response, b"GET / HTTP/1.1\r\nRange: bytes=-5\r\n\r\n");
+        response.remove_header("Last-Modified");
+
+        assert_eq!(
+            String::from_utf8(response.write()).unwrap(),
+            "HTTP/1.1 206 Partial Content\r\naccept-ranges: bytes\r\ncontent-length: 5\r\ncontent-range: bytes 6-10/11\r\ncontent-type: text/plain; charset=utf-8\r\n\r\nWorld"
+        );
+
+        let (response, _) = file_response("range_not_satisfiable");
+
+        let mut response =
+            apply_conditionals(response, b"GET / HTTP/1.1\r\nRange: bytes=20-30\r\n\r\n");
+        response.remove_header("Last-Modified");
+
+        assert_eq!(
+            String::from_utf8(response.write()).unwrap(),
+            "HTTP/1.1 416 Range Not Satisfiable\r\naccept-ranges: bytes\r\ncontent-length: 0\r\ncontent-range: bytes */11\r\n\r\n"
+        );
+
+        let mut response = Response::new(&Version::HTTP_1_1);
+        response.set_body("Hello World");
+
+        let response = apply_conditionals(response, b"GET / HTTP/1.1\r\nRange: bytes=0-4\r\n\r\n");
+
+        assert_eq!(response.status_code, StatusCode::OK);
+    }
+
+    #[test]
+    fn test_apply_conditionals_multiple_ranges() {
+        let (response, _) = file_response("multiple_ranges");
+
+        let response =
+            apply_conditionals(response, b"GET / HTTP/1.1\r\nRange: bytes=0-4, 6-\r\n\r\n");
+
+        let content_type = response.headers.header("Content-Type").unwrap().clone();
+        let boundary = content_type
+            .strip_prefix("multipart/byteranges; boundary=")
+            .unwrap();
+        let body = format!(
+            "--{0}\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Range: bytes 0-4/11\r\n\r\nHello\r\n--{0}\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Range: bytes 6-10/11\r\n\r\nWorld\r\n--{0}--\r\n",
+            boundary
+        );
+
+        assert_eq!(response.status_code, StatusCode::PartialContent);
+        assert_eq!(
+            response.headers.header("Content-Length"),
+            Some(&body.len().to_string())
+        );
+        assert_eq!(response.body().to_bytes().unwrap(), body.as_bytes());
+    }
+
+    #[test]
+    fn test_apply_conditionals_if_range() {
+        let (response, modified) = file_response("if_range");
+
+        let response = apply_conditionals(
+            response,
+            format!(
+                "GET / HTTP/1.1\r\nRange: bytes=0-4\r\nIf-Range: {}\r\n\r\n",
+                modified
+            )
+            .as_bytes(),
+        );
+
+        assert_eq!(response.status_code, StatusCode::PartialContent);
+
+        let (mut response, _) = file_response("if_range_etag");
+        response.set_typed_header(ETag(EntityTag::strong("1")));
+
+        let response = apply_conditionals(
+            response,
+            b"GET / HTTP/1.1\r\nRange: bytes=0-4\r\nIf-Range: \"2\"\r\n\r\n",
+        );
+
+        assert_eq!(response.status_code, StatusCode::OK);
+        assert_eq!(response.body().len(), 11);
+    }
+
+    #[test]
+    fn test_generate_etag() {
+        let mut response = Response::new(&Version::HTTP_1_1);
+        response.set_body("Hello World");
+        response.generate_etag(ETagMode::Strong);
+
+        let etag = response.header("ETag").unwrap();
+        assert_eq!(etag, "\"b-3d58dee72d4e0c27\"");
+
+        let mut other_response = Response::new(&Version::HTTP_1_1);
+        other_response.set_body("Hello World");
+        other_response.generate_etag(ETagMode::Weak);
+        assert_eq!(other_response.header("ETag"), Some(format!("W/{}", etag)));
+
+        let mut other_response = Response::new(&Version::HTTP_1_1);
+        other_response.set_body("Hello World!");
+        other_response.generate_etag(ETagMode::Strong);
+        assert_ne!(other_response.header("ETag"), Some(etag));
+
+        let mut response = Response::new(&Version::HTTP_1_1);
+        response.set_typed_header(ETag(EntityTag::strong("v1")));
+        response.set_body("Hello World");
+        response.generate_etag(ETagMode::Strong);
+        assert_eq!(response.header("ETag"), Some(String::from("\"v1\"")));
+
+        let mut response = Response::new(&Version::HTTP_1_1);
+        response.set(StatusCode::NotFound, String::from("Not Found"));
+        response.generate_etag(ETagMode::Strong);
+        assert_eq!(response.header("ETag"), None);
+    }
+
+    #[test]
+    fn test_apply_conditionals_etag() {
+        let mut response = Response::new(&Version::HTTP_1_1);
+        response.set_typed_header(ETag(EntityTag::strong("v1")));
+        response.set_body("Hello World");
+
+        let not_modified = apply_conditionals(
+            response.clone(),
+            b"GET / HTTP/1.1\r\nIf-None-Match: \"v0\", W/\"v1\"\r\n\r\n",
+        );
+        assert_eq!(
+            String::from_utf8(not_modified.write()).unwrap(),
+            "HTTP/1.1 304 Not Modified\r\netag: \"v1\"\r\n\r\n"
+        );
+
+        let modified = apply_conditionals(
+            response.clone(),
+            b"GET / HTTP/1.1\r\nIf-None-Match: \"v0\"\r\n\r\n",
+        );
+        assert_eq!(modified.status_code(), StatusCode::OK);
+
+        let failed = apply_conditionals(response, b"GET / HTTP/1.1\r\nIf-Match: W/\"v1\"\r\n\r\n");
+        assert_eq!(
+            String::from_utf8(failed.write()).unwrap(),
+            "HTTP/1.1 412 Precondition Failed\r\ncontent-length: 0\r\netag: \"v1\"\r\n\r\n"
+        );
+    }
+
+    #[test]
+    fn test_set_max_age() {
+        let mut response = Response::new(&Version::HTTP_1_1);
+        response.set_typed_header(CacheControl::new().set_public(true));
+        response.set_max_age(Duration::from_secs(60));
+
+        assert_eq!(
+            response.header("Cache-Control"),
+            Some(String::from("public, max-age=60"))
+        );
+
+        let expires = HttpDate::from_string(&response.header("Expires").unwrap()).unwrap();
+        let now = HttpDate::now().unix_timestamp();
+
+        assert!((now + 59..=now + 60).contains(&expires.unix_timestamp()));
+
+        response.set_max_age(Duration::MAX);
+
+        assert_eq!(
+            response.header("Expires"),
+            Some(String::from("Fri, 31 Dec 9999 23:59:59 GMT"))
+        );
+    }
+
+    #[test]
+    fn test_set_default_cache_control() {
+        let cache_control = CacheControl::new().set_max_age(Duration::from_secs(60));
+
+        let mut response = Response::new(&Version::HTTP_1_1);
+        response.set_default_cache_control(&cache_control);
+        assert_eq!(
+            response.header("Cache-Control"),
+            Some(String::from("max-age=60"))
+        );
+
+        let mut response = Response::new(&Version::HTTP_1_1);
+        response.set_typed_header(CacheControl::new().set_no_store(true));
+        response.set_default_cache_control(&cache_control);
+        assert_eq!(
+            response.header("Cache-Control"),
+            Some(String::from("no-store"))
+        );
+
+        let mut response = Response::new(&Version::HTTP_1_1);
+        response.set_status_code(StatusCode::NotFound);
+        response.set_default_cache_control(&cache_control);
+        assert_eq!(response.header("Cache-Control"), None);
+    }
+
+    #[test]
+    fn test_redirect() {
+        let mut response = Response::new(&Version::HTTP_1_1);
+        response.see_other("/tasks?filter=to do");
+
+        assert_eq!(
+            String::from_utf8(response.write()).unwrap(),
+            "HTTP/1.1 303 See Other\r\nlocation: /tasks?filter=to%20do\r\n\r\n"
+        );
+
+        for (status_code, location) in [
+            (StatusCode::MovedPermanently, "https://example.com/"),
+            (StatusCode::Found, "/found"),
+            (StatusCode::TemporaryRedirect, "/temporary"),
+            (StatusCode::PermanentRedirect, "/permanent"),
+        ] {
+            let mut response = Response::new(&Version::HTTP_1_1);
+
+            match status_code {
+                StatusCode::MovedPermanently => response.moved_permanently(location),
+                StatusCode::Found => response.found(location),
+                StatusCode::TemporaryRedirect => response.temporary_redirect(location),
+                _ => response.permanent_redirect(location),
+            };
+
+            assert_eq!(response.status_code(), status_code);
+            assert_eq!(response.header("Location"), Some(String::from(location)));
+        }
+    }
+
+    #[test]
+    fn test_redirect_invalid() {
+        let mut response = Response::new(&Version::HTTP_1_1);
+        response.found("/\r\nSet-Cookie: a=b");
+
+        assert_eq!(response.status_code(), StatusCode::InternalServerError);
+        assert_eq!(response.header("Location"), None);
+
+        let mut response = Response::new(&Version::HTTP_1_1);
+        response.redirect(StatusCode::OK, "/");
+
+        assert_eq!(response.status_code(), StatusCode::InternalServerError);
+        assert_eq!(response.header("Location"), None);
+    }
 }
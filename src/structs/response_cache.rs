@@ -0,0 +1,518 @@
+use crate::{
+    utils::macros::unwrap_error, Body, CacheControl, CacheEntry, Method, Request, Response,
+    StatusCode, TypedHeader,
+};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+
+type CacheKey = (String, String);
+
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+    max_entries: usize,
+    max_size: u64,
+    entries: Arc<Mutex<HashMap<CacheKey, Vec<CacheEntry>>>>,
+}
+
+impl ResponseCache {
+    pub fn new() -> Self {
+        Self {
+            max_entries: 1024,
+            max_size: 64 * 1024 * 1024,
+            entries: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn max_entries(&self) -> usize {
+        self.max_entries
+    }
+
+    pub fn max_size(&self) -> u64 {
+        self.max_size
+    }
+
+    pub fn set_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries;
+        self
+    }
+
+    pub fn set_max_size(mut self, max_size: u64) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries().values().map(Vec::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn size(&self) -> u64 {
+        Self::total_size(&self.entries())
+    }
+
+    pub fn invalidate(&self, method: Method, url: &str) {
+        self.entries()
+            .remove(&(method.to_string(), String::from(url)));
+    }
+
+    pub fn invalidate_prefix(&self, prefix: &str) {
+        self.entries().retain(|(_, url), _| {
+            !url.split('?')
+                .next()
+                .unwrap_or_default()
+                .starts_with(prefix)
+        });
+    }
+
+    pub fn clear(&self) {
+        self.entries().clear();
+    }
+
+    pub(crate) fn get(&self, request: &Request) -> Option<Response> {
+        if !Self::is_cacheable_request(request) {
+            return None;
+        }
+
+        let request_cache_control = request.typed_header::<CacheControl>();
+
+        if request_cache_control
+            .is_some_and(|cache_control| cache_control.is_no_cache() || cache_control.is_no_store())
+            || request
+                .header("Pragma")
+                .is_some_and(|pragma| pragma.eq_ignore_ascii_case("no-cache"))
+        {
+            return None;
+        }
+
+        let mut entries = self.entries();
+        let variants = entries.get_mut(&Self::key(request))?;
+        variants.retain(CacheEntry::is_fresh);
+
+        variants
+            .iter_mut()
+            .find(|entry| entry.matches(request))
+            .map(CacheEntry::response)
+    }
+
+    pub(crate) fn store(&self, request: &Request, response: &Response) {
+        if !Self::is_cacheable_request(request)
+            || request
+                .typed_header::<CacheControl>()
+                .is_some_and(|cache_control| cache_control.is_no_store())
+        {
+            return;
+        }
+
+        let Some(ttl) = Self::ttl(request, response) else {
+            return;
+        };
+
+        let Some(vary) = Self::vary(request, response) else {
+            return;
+        };
+
+        let Some(entry) = CacheEntry::new(response.clone(), vary, ttl) else {
+            return;
+        };
+
+        if entry.size() > self.max_size || self.max_entries == 0 {
+            return;
+        }
+
+        let mut entries = self.entries();
+        let variants = entries.entry(Self::key(request)).or_default();
+        variants.retain(|variant| variant.is_fresh() && variant.vary() != entry.vary());
+        variants.push(entry);
+
+        self.evict(&mut entries);
+    }
+
+    fn entries(&self) -> MutexGuard<'_, HashMap<CacheKey, Vec<CacheEntry>>> {
+        unwrap_error!(self.entries.lock(), "Failed to lock response cache")
+    }
+
+    fn key(request: &Request) -> CacheKey {
+        (
+            request.method().to_string(),
+            request.path().original_url().clone(),
+        )
+    }
+
+    fn is_cacheable_request(request: &Request) -> bool {
+        matches!(request.method(), Method::GET | Method::HEAD)
+    }
+
+    fn ttl(request: &Request, response: &Response) -> Option<Duration> {
+        if response.status_code() != StatusCode::OK
+            || !matches!(response.body(), Body::Bytes(_))
+            || response.has_cookies()
+        {
+            return None;
+        }
+
+        let cache_control = match response.header(CacheControl::name()) {
+            Some(value) => CacheControl::from_value(&value).ok()?,
+            None => CacheControl::new(),
+        };
+
+        if cache_control.is_no_store() || cache_control.is_no_cache() || cache_control.is_private()
+        {
+            return None;
+        }
+
+        if (request.header("Authorization").is_some() || request.header("Cookie").is_some())
+            && !cache_control.is_public()
+            && cache_control.s_maxage().is_none()
+        {
+            return None;
+        }
+
+        let ttl = cache_control.s_maxage().or(cache_control.max_age())?;
+
+        (!ttl.is_zero()).then_some(ttl)
+    }
+
+    fn vary(request: &Request, response: &Response) -> Option<Vec<(String, Option<String>)>> {
+        let mut vary = vec![];
+
+        for value in response.header_all("Vary") {
+            for name in value.split(',') {
+                let name = name.trim().to_lowercase();
+
+                if name == "*" {
+                    return None;
+                }
+
+                if !name.is_empty() && !vary.iter().any(|(vary_name, _)| vary_name == &name) {
+                    let value = request.header_joined(&name);
+                    vary.push((name, value));
+                }
+            }
+        }
+
+        Some(vary)
+    }
+
+    fn total_size(entries: &HashMap<CacheKey, Vec<CacheEntry>>) -> u64 {
+        entries.values().flatten().map(CacheEntry::size).sum()
+    }
+
+    fn evict(&self, entries: &mut HashMap<CacheKey, Vec<CacheEntry>>) {
+        entries.retain(|_, variants| {
+            variants.retain(CacheEntry::is_fresh);
+            !variants.is_empty()
+        });
+
+        let mut count: usize = entries.values().map(Vec::len).sum();
+        let mut size = Self::total_size(entries);
+
+        while count > self.max_entries || size > self.max_size {
+            let Some((key, index)) = entries
+                .iter()
+                .flat_map(|(key, variants)| {
+                    variants
+                        .iter()
+                        .enumerate()
+                        .map(move |(index, entry)| (key, index, entry.last_used()))
+                })
+                .min_by_key(|(_, _, last_used)| *last_used)
+                .map(|(key, index, _)| (key.clone(), index))
+            else {
+                break;
+            };
+
+            if let Some(variants) = entries.get_mut(&key) {
+                let entry = variants.remove(index);
+                count -= 1;
+                size -= entry.size();
+
+                if variants.is_empty() {
+                    entries.remove(&key);
+                }
+            }
+        }
+    }
+}
+
+impl Default for ResponseCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{request_from_bytes, NormalizationConfig, Version};
+    use std::thread;
+
+    fn response(body: &str) -> Response {
+        let mut response = Response::new(&Version::HTTP_1_1);
+        response.set_header("Cache-Control", "max-age=60");
+        response.set_body(body);
+        response
+    }
+
+    fn request(request: &str) -> Request {
+        request_from_bytes(request.as_bytes()).unwrap()
+    }
+
+    fn body(response: Response) -> String {
+        match response.body() {
+            Body::Bytes(bytes) => String::from_utf8(bytes.clone()).unwrap(),
+            _ => panic!("Expected bytes body"),
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let cache = ResponseCache::new().set_max_entries(10).set_max_size(1024);
+
+        assert_eq!(cache.max_entries(), 10);
+        assert_eq!(cache.max_size(), 1024);
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_store_and_get() {
+        let cache = ResponseCache::new();
+        let get = request("GET /hello?name=world HTTP/1.1\r\n\r\n");
+
+        cache.store(&get, &response("Hello"));
+
+        let cached = cache.get(&get).unwrap();
+        assert_eq!(cached.header("Age"), Some(String::from("0")));
+        assert_eq!(body(cached), "Hello");
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.size(), 5);
+
+        assert!(cache.get(&request("GET /hello HTTP/1.1\r\n\r\n")).is_none());
+        assert!(cache
+            .get(&request("HEAD /hello?name=world HTTP/1.1\r\n\r\n"))
+            .is_none());
+        assert!(cache
+            .get(&request(
+                "GET /hello?name=world HTTP/1.1\r\nCache-Control: no-cache\r\n\r\n"
+            ))
+            .is_none());
+    }
+
+    #[test]
+    fn test_store_not_cacheable() {
+        let cache = ResponseCache::new();
+
+        cache.store(&request("POST / HTTP/1.1\r\n\r\n"), &response("Hello"));
+
+        let mut not_found = response("Not found");
+        not_found.set_status_code(StatusCode::NotFound);
+        cache.store(&request("GET /missing HTTP/1.1\r\n\r\n"), &not_found);
+
+        let mut no_store = response("Hello");
+        no_store.set_header("Cache-Control", "no-store");
+        cache.store(&request("GET /no-store HTTP/1.1\r\n\r\n"), &no_store);
+
+        let mut private = response("Hello");
+        private.set_header("Cache-Control", "private, max-age=60");
+        cache.store(&request("GET /private HTTP/1.1\r\n\r\n"), &private);
+
+        let mut vary = response("Hello");
+        vary.set_header("Vary", "*");
+        cache.store(&request("GET /vary HTTP/1.1\r\n\r\n"), &vary);
+
+        cache.store(
+            &request("GET /auth HTTP/1.1\r\nAuthorization: Basic YTpi\r\n\r\n"),
+            &response("Hello"),
+        );
+
+        cache.store(
+            &request("GET /request HTTP/1.1\r\nCache-Control: no-store\r\n\r\n"),
+            &response("Hello"),
+        );
+
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_ttl() {
+        let cache = ResponseCache::new();
+        let get = request("GET / HTTP/1.1\r\n\r\n");
+
+        let mut implicit = response("Hello");
+        implicit.remove_header("Cache-Control");
+        cache.store(&get, &implicit);
+        assert!(cache.is_empty());
+
+        let mut public = response("Hello");
+        public.set_header("Cache-Control", "public");
+        cache.store(&get, &public);
+        assert!(cache.is_empty());
+
+        cache.store(&get, &response("Hello"));
+        assert!(cache.get(&get).is_some());
+
+        let mut huge = response("Hello");
+        huge.set_header("Cache-Control", "max-age=18446744073709551615");
+        cache.invalidate(Method::GET, "/");
+        cache.store(&get, &huge);
+        assert!(cache.is_empty());
+
+        let mut zero = response("Hello");
+        zero.set_header("Cache-Control", "max-age=0");
+        cache.invalidate(Method::GET, "/");
+        cache.store(&get, &zero);
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_cookie_request() {
+        let cache = ResponseCache::new();
+        let alice = request("GET /profile HTTP/1.1\r\nCookie: session=alice\r\n\r\n");
+        let bob = request("GET /profile HTTP/1.1\r\nCookie: session=bob\r\n\r\n");
+
+        cache.store(&alice, &response("Alice"));
+        assert!(cache.get(&bob).is_none());
+        assert!(cache.is_empty());
+
+        let mut shared = response("Profile");
+        shared.set_header("Cache-Control", "public, max-age=60");
+        cache.store(&alice, &shared);
+        assert_eq!(body(cache.get(&bob).unwrap()), "Profile");
+
+        cache.clear();
+
+        let mut shared = response("Profile");
+        shared.set_header("Cache-Control", "s-maxage=60");
+        cache.store(&bob, &shared);
+        assert_eq!(body(cache.get(&alice).unwrap()), "Profile");
+    }
+
+    #[test]
+    fn test_vary() {
+        let cache = ResponseCache::new();
+        let polish = request("GET / HTTP/1.1\r\nAccept-Language: pl\r\n\r\n");
+        let english = request("GET / HTTP/1.1\r\nAccept-Language: en\r\n\r\n");
+
+        let mut response_pl = response("Cześć");
+        response_pl.set_header("Vary", "Accept-Language");
+        cache.store(&polish, &response_pl);
+
+        assert!(cache.get(&english).is_none());
+
+        let mut response_en = response("Hello");
+        response_en.set_header("Vary", "Accept-Language");
+        cache.store(&english, &response_en);
+
+        assert_eq!(body(cache.get(&polish).unwrap()), "Cześć");
+        assert_eq!(body(cache.get(&english).unwrap()), "Hello");
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn test_vary_multiple_headers() {
+        let cache = ResponseCache::new();
+        let gzip = request("GET / HTTP/1.1\r\nAccept-Encoding: gzip\r\nX-User: alice\r\n\r\n");
+        let other_user = request("GET / HTTP/1.1\r\nAccept-Encoding: gzip\r\nX-User: bob\r\n\r\n");
+
+        let mut varied = response("Alice");
+        varied.append_header("Vary", "Accept-Encoding");
+        varied.append_header("Vary", "X-User, accept-encoding");
+        cache.store(&gzip, &varied);
+
+        assert_eq!(body(cache.get(&gzip).unwrap()), "Alice");
+        assert!(cache.get(&other_user).is_none());
+
+        let mut wildcard = response("Hello");
+        wildcard.append_header("Vary", "Accept-Encoding");
+        wildcard.append_header("Vary", "*");
+        cache.clear();
+        cache.store(&gzip, &wildcard);
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_eviction() {
+        let cache = ResponseCache::new().set_max_entries(2);
+        let a = request("GET /a HTTP/1.1\r\n\r\n");
+        let b = request("GET /b HTTP/1.1\r\n\r\n");
+        let c = request("GET /c HTTP/1.1\r\n\r\n");
+
+        cache.store(&a, &response("a"));
+        thread::sleep(Duration::from_millis(1));
+        cache.store(&b, &response("b"));
+        thread::sleep(Duration::from_millis(1));
+        cache.get(&a);
+        thread::sleep(Duration::from_millis(1));
+        cache.store(&c, &response("c"));
+
+        assert!(cache.get(&a).is_some());
+        assert!(cache.get(&b).is_none());
+        assert!(cache.get(&c).is_some());
+
+        let cache = ResponseCache::new().set_max_size(8);
+
+        cache.store(&a, &response("aaaa"));
+        thread::sleep(Duration::from_millis(1));
+        cache.store(&b, &response("bbbb"));
+        thread::sleep(Duration::from_millis(1));
+        cache.store(&c, &response("cccc"));
+        cache.store(&request("GET /d HTTP/1.1\r\n\r\n"), &response("too large"));
+
+        assert!(cache.get(&a).is_none());
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.size(), 8);
+    }
+
+    #[test]
+    fn test_invalidate() {
+        let cache = ResponseCache::new();
+
+        cache.store(&request("GET /tasks HTTP/1.1\r\n\r\n"), &response("[]"));
+        cache.store(
+            &request("GET /tasks?page=2 HTTP/1.1\r\n\r\n"),
+            &response("[]"),
+        );
+        cache.store(&request("GET /task/1 HTTP/1.1\r\n\r\n"), &response("{}"));
+        cache.store(&request("GET /users HTTP/1.1\r\n\r\n"), &response("[]"));
+
+        cache.invalidate(Method::GET, "/tasks?page=2");
+        assert_eq!(cache.len(), 3);
+
+        cache.invalidate_prefix("/task");
+        assert_eq!(cache.len(), 1);
+
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_normalized_key() {
+        let cache = ResponseCache::new();
+        let normalization = NormalizationConfig::new().set_case_folding(true);
+
+        let mut first = request("GET /Tasks//1?page=2 HTTP/1.1\r\n\r\n");
+        first.rewrite(&normalization, &[]).unwrap();
+        cache.store(&first, &response("{}"));
+
+        let mut second = request("GET /tasks/./1?page=2 HTTP/1.1\r\n\r\n");
+        second.rewrite(&normalization, &[]).unwrap();
+        assert_eq!(body(cache.get(&second).unwrap()), "{}");
+        assert_eq!(cache.len(), 1);
+
+        let mut third = request("GET /TASKS/1 HTTP/1.1\r\n\r\n");
+        third.rewrite(&normalization, &[]).unwrap();
+        cache.store(&third, &response("{}"));
+        assert_eq!(cache.len(), 2);
+
+        cache.invalidate(Method::GET, "/tasks/1?page=2");
+        assert_eq!(cache.len(), 1);
+
+        cache.invalidate_prefix("/tasks/");
+        assert!(cache.is_empty());
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{Path, Request, SerwerError};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RewriteRule {
+    from: Path,
+    to: String,
+}
+
+impl RewriteRule {
+    pub fn new(from: &str, to: &str) -> Result<Self, SerwerError> {
+        let from = Path::from_string(from)?;
+
+        if !to.starts_with('/') {
+            return Err(SerwerError::PathMissingLeadingSlash(String::from(to)));
+        }
+
+        Ok(Self {
+            from,
+            to: String::from(to),
+        })
+    }
+
+    pub fn from(&self) -> &Path {
+        &self.from
+    }
+
+    pub fn to(&self) -> &String {
+        &self.to
+    }
+
+    pub(crate) fn rewrite(&self, request: &Request) -> Option<String> {
+        let (matches, params) = self.from.matches(request.path());
+
+        if !matches {
+            return None;
+        }
+
+        let url = params.unwrap().fill(&self.to)?;
+
+        match request.original_url().split_once('?') {
+            Some((_, query)) if !url.contains('?') => Some(format!("{}?{}", url, query)),
+            _ => Some(url),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request_from_bytes;
+
+    #[test]
+    fn test_new() {
+        let rule = RewriteRule::new("/u/<id>", "/user/<id>").unwrap();
+
+        assert_eq!(rule.from(), &Path::from_string("/u/<id>").unwrap());
+        assert_eq!(rule.to(), "/user/<id>");
+
+        assert!(matches!(
+            RewriteRule::new("/u/<id>", "user"),
+            Err(SerwerError::PathMissingLeadingSlash(error_string)) if &error_string == "user"
+        ));
+    }
+
+    #[test]
+    fn test_rewrite() {
+        let rule = RewriteRule::new("/u/<id>", "/user/<id>").unwrap();
+
+        let request = request_from_bytes(b"GET /u/1?tab=posts HTTP/1.1\r\n\r\n").unwrap();
+        assert_eq!(
+            rule.rewrite(&request),
+            Some(String::from("/user/1?tab=posts"))
+        );
+
+        let request = request_from_bytes(b"GET /user/1 HTTP/1.1\r\n\r\n").unwrap();
+        assert_eq!(rule.rewrite(&request), None);
+
+        let rule = RewriteRule::new("/latest", "/posts?sort=date").unwrap();
+
+        let request = request_from_bytes(b"GET /latest?sort=name HTTP/1.1\r\n\r\n").unwrap();
+        assert_eq!(
+            rule.rewrite(&request),
+            Some(String::from("/posts?sort=date"))
+        );
+    }
+}
@@ -1,10 +1,13 @@
-use crate::{Action, Method, Path, Request, Response, SerwerError};
+use crate::{Action, CacheControl, Method, Path, Request, Response, SerwerError};
+use std::any::TypeId;
 
 #[derive(Debug)]
 pub struct Route {
     method: Method,
     path: Path,
     action: Action,
+    required_states: Vec<(TypeId, &'static str)>,
+    cache_control: Option<CacheControl>,
 }
 
 impl Route {
@@ -16,9 +19,20 @@ impl Route {
             method,
             path: Path::from_string(&String::from(path))?,
             action: Action::new(action),
+            required_states: vec![],
+            cache_control: None,
         })
     }
 
+    pub fn set_required_states(mut self, required_states: Vec<(TypeId, &'static str)>) -> Self {
+        self.required_states = required_states;
+        self
+    }
+
+    pub fn set_cache_control(&mut self, cache_control: CacheControl) {
+        self.cache_control = Some(cache_control);
+    }
+
     pub fn method(&self) -> &Method {
         &self.method
     }
@@ -27,6 +41,14 @@ impl Route {
         &self.path
     }
 
+    pub fn required_states(&self) -> &Vec<(TypeId, &'static str)> {
+        &self.required_states
+    }
+
+    pub fn cache_control(&self) -> Option<&CacheControl> {
+        self.cache_control.as_ref()
+    }
+
     pub fn run_action(&self, request: Request) -> Response {
         self.action.run(request)
     }
@@ -46,6 +68,26 @@ mod tests {
             route.path(),
             &Path::from_string(&String::from("/")).unwrap()
         );
+        assert!(route.required_states().is_empty());
+        assert_eq!(route.cache_control(), None);
+    }
+
+    #[test]
+    fn test_set_required_states() {
+        let route = Route::new(Method::GET, "/", |_, res| res)
+            .unwrap()
+            .set_required_states(vec![(TypeId::of::<u32>(), "u32")]);
+        assert_eq!(route.required_states(), &vec![(TypeId::of::<u32>(), "u32")]);
+    }
+
+    #[test]
+    fn test_set_cache_control() {
+        let mut route = Route::new(Method::GET, "/", |_, res| res).unwrap();
+        route.set_cache_control(CacheControl::new().set_no_store(true));
+        assert_eq!(
+            route.cache_control(),
+            Some(&CacheControl::new().set_no_store(true))
+        );
     }
 
     #[test]
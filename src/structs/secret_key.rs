@@ -0,0 +1,198 @@
+use crate::{base64_decode, base64_encode, SerwerError};
+#[cfg(feature = "private")]
+use aes_gcm::{
+    aead::{Aead, AeadCore, KeyInit, OsRng, Payload},
+    Aes256Gcm, Nonce,
+};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::fmt;
+
+const MIN_SECRET_LENGTH: usize = 32;
+
+#[cfg(feature = "signed")]
+const SIGNATURE_LENGTH: usize = 43;
+
+#[cfg(feature = "private")]
+const NONCE_LENGTH: usize = 12;
+
+#[derive(Clone, PartialEq)]
+pub struct SecretKey {
+    #[cfg(feature = "signed")]
+    signing: [u8; 32],
+    #[cfg(feature = "private")]
+    encryption: [u8; 32],
+}
+
+impl SecretKey {
+    pub fn from_secret(secret: &[u8]) -> Result<Self, SerwerError> {
+        if secret.len() < MIN_SECRET_LENGTH {
+            return Err(SerwerError::InvalidSecretKey(secret.len().to_string()));
+        }
+
+        Ok(Self {
+            #[cfg(feature = "signed")]
+            signing: Self::derive(secret, b"serwer-cookie-signing"),
+            #[cfg(feature = "private")]
+            encryption: Self::derive(secret, b"serwer-cookie-encryption"),
+        })
+    }
+
+    #[cfg(feature = "signed")]
+    pub(crate) fn sign(&self, name: &str, value: &str) -> String {
+        let mac = self.mac(name, value);
+
+        format!(
+            "{}{}",
+            base64_encode(&mac.finalize().into_bytes(), true),
+            value
+        )
+    }
+
+    #[cfg(feature = "signed")]
+    pub(crate) fn verify(&self, name: &str, value: &str) -> Option<String> {
+        if !value.is_char_boundary(SIGNATURE_LENGTH) {
+            return None;
+        }
+
+        let (signature, value) = value.split_at(SIGNATURE_LENGTH);
+        let signature = base64_decode(signature, true).ok()?;
+
+        self.mac(name, value)
+            .verify_slice(&signature)
+            .ok()
+            .map(|_| String::from(value))
+    }
+
+    #[cfg(feature = "private")]
+    pub(crate) fn encrypt(&self, name: &str, value: &str) -> String {
+        let cipher = Aes256Gcm::new(&self.encryption.into());
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let payload = Payload {
+            msg: value.as_bytes(),
+            aad: name.as_bytes(),
+        };
+        let ciphertext = cipher
+            .encrypt(&nonce, payload)
+            .expect("AES-GCM encryption does not fail for cookie sized values");
+
+        let mut bytes = nonce.to_vec();
+        bytes.extend(ciphertext);
+
+        base64_encode(&bytes, true)
+    }
+
+    #[cfg(feature = "private")]
+    pub(crate) fn decrypt(&self, name: &str, value: &str) -> Option<String> {
+        let bytes = base64_decode(value, true).ok()?;
+
+        if bytes.len() < NONCE_LENGTH {
+            return None;
+        }
+
+        let (nonce, ciphertext) = bytes.split_at(NONCE_LENGTH);
+        let cipher = Aes256Gcm::new(&self.encryption.into());
+        let payload = Payload {
+            msg: ciphertext,
+            aad: name.as_bytes(),
+        };
+        let plaintext = cipher.decrypt(Nonce::from_slice(nonce), payload).ok()?;
+
+        String::from_utf8(plaintext).ok()
+    }
+
+    #[cfg(feature = "signed")]
+    fn mac(&self, name: &str, value: &str) -> Hmac<Sha256> {
+        let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(&self.signing)
+            .expect("HMAC accepts keys of any length");
+        mac.update(name.as_bytes());
+        mac.update(b"=");
+        mac.update(value.as_bytes());
+
+        mac
+    }
+
+    fn derive(secret: &[u8], purpose: &[u8]) -> [u8; 32] {
+        let mut mac =
+            <Hmac<Sha256> as Mac>::new_from_slice(secret).expect("HMAC accepts keys of any length");
+        mac.update(purpose);
+
+        mac.finalize().into_bytes().into()
+    }
+}
+
+impl fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SecretKey").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &[u8] = b"0123456789abcdef0123456789abcdef";
+
+    #[test]
+    fn test_from_secret_too_short() {
+        let result = SecretKey::from_secret(b"secret");
+        assert!(
+            matches!(result, Err(SerwerError::InvalidSecretKey(error_string)) if &error_string == "6")
+        );
+    }
+
+    #[test]
+    fn test_debug() {
+        let key = SecretKey::from_secret(SECRET).unwrap();
+        assert_eq!(format!("{:?}", key), "SecretKey { .. }");
+    }
+
+    #[cfg(feature = "signed")]
+    #[test]
+    fn test_sign() {
+        let key = SecretKey::from_secret(SECRET).unwrap();
+        let signed = key.sign("user", "42");
+
+        assert_eq!(signed.len(), SIGNATURE_LENGTH + 2);
+        assert!(signed.ends_with("42"));
+        assert_eq!(key.verify("user", &signed), Some(String::from("42")));
+    }
+
+    #[cfg(feature = "signed")]
+    #[test]
+    fn test_verify_tampered() {
+        let key = SecretKey::from_secret(SECRET).unwrap();
+        let signed = key.sign("user", "42");
+
+        assert_eq!(key.verify("user", &signed.replace("42", "43")), None);
+        assert_eq!(key.verify("admin", &signed), None);
+        assert_eq!(key.verify("user", "42"), None);
+
+        let other = SecretKey::from_secret(b"fedcba9876543210fedcba9876543210").unwrap();
+        assert_eq!(other.verify("user", &signed), None);
+    }
+
+    #[cfg(feature = "private")]
+    #[test]
+    fn test_encrypt() {
+        let key = SecretKey::from_secret(SECRET).unwrap();
+        let encrypted = key.encrypt("user", "42");
+
+        assert_ne!(encrypted, key.encrypt("user", "42"));
+        assert_eq!(key.decrypt("user", &encrypted), Some(String::from("42")));
+    }
+
+    #[cfg(feature = "private")]
+    #[test]
+    fn test_decrypt_tampered() {
+        let key = SecretKey::from_secret(SECRET).unwrap();
+        let encrypted = key.encrypt("user", "42");
+
+        assert_eq!(key.decrypt("admin", &encrypted), None);
+        assert_eq!(key.decrypt("user", "42"), None);
+        assert_eq!(key.decrypt("user", "AAAA"), None);
+
+        let other = SecretKey::from_secret(b"fedcba9876543210fedcba9876543210").unwrap();
+        assert_eq!(other.decrypt("user", &encrypted), None);
+    }
+}
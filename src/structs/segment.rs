@@ -1,4 +1,4 @@
-use crate::{decode, SerwerError};
+use crate::{decode_path, SerwerError};
 
 const ALLOWED_CHARACTERS: &str =
     "%-.0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ_abcdefghijklmnopqrstuvwxyz~";
@@ -24,19 +24,18 @@ impl Segment {
 
         if !parsed_string
             .chars()
-            .all(|c| ALLOWED_CHARACTERS.contains(c))
+            .all(|c| ALLOWED_CHARACTERS.contains(c) || !c.is_ascii())
         {
             return Err(SerwerError::InvalidPathSegmentCharacters(String::from(
                 string,
             )));
         }
 
-        parsed_string = decode(&parsed_string)?;
+        parsed_string = decode_path(&parsed_string)?;
 
-        if !parsed_string
-            .chars()
-            .all(|c| ALLOWED_CHARACTERS_WITH_RESERVED.contains(c))
-        {
+        if !parsed_string.chars().all(|c| {
+            ALLOWED_CHARACTERS_WITH_RESERVED.contains(c) || !(c.is_ascii() || c.is_control())
+        }) {
             return Err(SerwerError::InvalidPathSegmentCharacters(String::from(
                 string,
             )));
@@ -129,6 +128,34 @@ mod tests {
             }
         );
 
+        let string = &String::from("caf%C3%A9");
+        let result = Segment::from_string(string);
+        assert_eq!(
+            result.unwrap(),
+            Segment {
+                name: String::from("café"),
+                is_param: false
+            }
+        );
+
+        let string = &String::from("zażółć+");
+        let result = Segment::from_string(string);
+        assert!(matches!(
+            result,
+            Err(SerwerError::InvalidPathSegmentCharacters(error_string)) if &error_string == "zażółć+"
+        ));
+
+        let string = &String::from("zażółć");
+        let result = Segment::from_string(string);
+        assert_eq!(result.unwrap().name(), "zażółć");
+
+        let string = &String::from("%C2%85");
+        let result = Segment::from_string(string);
+        assert!(matches!(
+            result,
+            Err(SerwerError::InvalidPathSegmentCharacters(error_string)) if &error_string == "%C2%85"
+        ));
+
         let string = &String::from("us%20er");
         let result = Segment::from_string(string);
         assert!(matches!(
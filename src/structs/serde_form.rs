@@ -0,0 +1,56 @@
+use crate::{Form, FromRequest, PairsDeserializer, Request, SerwerError};
+use serde::de::DeserializeOwned;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SerdeForm<T>(pub T);
+
+impl<T: DeserializeOwned> FromRequest for SerdeForm<T> {
+    fn from_request(request: &Request) -> Result<Self, SerwerError> {
+        let form = request.form::<Form>()?;
+
+        Ok(Self(PairsDeserializer::deserialize(
+            form.fields().iter().map(|(name, value)| (name, value)),
+        )?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{request_from_bytes, StatusCode};
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Login {
+        username: String,
+        remember: Option<bool>,
+    }
+
+    #[test]
+    fn test_from_request() {
+        let request = request_from_bytes(
+            b"POST / HTTP/1.1\r\nContent-Type: application/x-www-form-urlencoded\r\nContent-Length: 27\r\n\r\nusername=john&remember=true",
+        )
+        .unwrap();
+
+        assert_eq!(
+            SerdeForm::<Login>::from_request(&request).unwrap(),
+            SerdeForm(Login {
+                username: String::from("john"),
+                remember: Some(true),
+            })
+        );
+
+        let request = request_from_bytes(
+            b"POST / HTTP/1.1\r\nContent-Type: application/x-www-form-urlencoded\r\nContent-Length: 13\r\n\r\nremember=true",
+        )
+        .unwrap();
+
+        assert_eq!(
+            SerdeForm::<Login>::from_request(&request)
+                .unwrap_err()
+                .status_code(),
+            StatusCode::UnprocessableEntity
+        );
+    }
+}
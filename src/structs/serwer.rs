@@ -1,8 +1,13 @@
+#[cfg(any(feature = "signed", feature = "private"))]
+use crate::SecretKey;
 use crate::{
     utils::macros::{custom_panic, generate_route, unwrap_error, unwrap_none},
-    Method, Request, Response, Route, ThreadPool,
+    CacheControl, CompressionConfig, Config, CookieEncoding, Data, DecompressionConfig, ETagMode,
+    Handler, Method, NormalizationConfig, Path, RedirectRule, Request, Response, ResponseCache,
+    RewriteRule, Route, SerwerError, StaticFiles, StatusCode, ThreadPool, TrailingSlash,
 };
 use std::{
+    any::TypeId,
     net::TcpListener,
     sync::{Arc, RwLock},
     thread,
@@ -11,6 +16,7 @@ use std::{
 #[derive(Debug)]
 pub struct Serwer {
     routes: Arc<RwLock<Vec<Route>>>,
+    config: Config,
     listener: Option<TcpListener>,
     thread_pool: Option<ThreadPool>,
 }
@@ -26,6 +32,7 @@ impl Serwer {
 
         Self {
             routes: Arc::new(RwLock::new(vec![])),
+            config: Config::new(),
             listener: None,
             thread_pool: None,
         }
@@ -42,6 +49,103 @@ impl Serwer {
     generate_route!(trace, Method::TRACE);
     generate_route!(patch, Method::PATCH);
 
+    #[track_caller]
+    pub fn route<H, Args>(&mut self, method: Method, path: &'static str, handler: H)
+    where
+        H: Handler<Args>,
+    {
+        self.add_route(
+            method,
+            path,
+            move |request, response| handler.call(request, response),
+            H::required_states(),
+        );
+    }
+
+    #[track_caller]
+    pub fn mount(&mut self, prefix: &str, static_files: StaticFiles) {
+        unwrap_error!(
+            self.add_mount(prefix, static_files),
+            "Error while mounting static files"
+        );
+    }
+
+    pub fn cache(&mut self, cache: ResponseCache) {
+        self.config.set_cache(cache);
+    }
+
+    #[track_caller]
+    pub fn cache_control(&mut self, method: Method, path: &str, cache_control: CacheControl) {
+        unwrap_error!(
+            self.set_route_cache_control(method, path, cache_control),
+            "Error while setting cache control"
+        );
+    }
+
+    #[track_caller]
+    pub fn redirect(&mut self, from: &str, to: &str, status_code: StatusCode) {
+        unwrap_error!(
+            self.add_redirect(from, to, status_code),
+            "Error while adding redirect"
+        );
+    }
+
+    pub fn redirect_trailing_slash(&mut self, trailing_slash: TrailingSlash) {
+        self.config
+            .add_redirect(RedirectRule::TrailingSlash(trailing_slash));
+    }
+
+    pub fn redirect_https(&mut self, port: Option<u16>) {
+        self.config
+            .add_redirect(RedirectRule::Https { host: None, port });
+    }
+
+    #[track_caller]
+    pub fn redirect_https_to(&mut self, host: &str, port: Option<u16>) {
+        unwrap_error!(
+            self.add_https_redirect(host, port),
+            "Error while adding HTTPS redirect"
+        );
+    }
+
+    pub fn normalization(&mut self, normalization: NormalizationConfig) {
+        self.config.set_normalization(normalization);
+    }
+
+    #[track_caller]
+    pub fn rewrite(&mut self, from: &str, to: &str) {
+        self.config.add_rewrite(unwrap_error!(
+            RewriteRule::new(from, to),
+            "Error while adding rewrite"
+        ));
+    }
+
+    pub fn cookie_encoding(&mut self, cookie_encoding: CookieEncoding) {
+        self.config.set_cookie_encoding(cookie_encoding);
+    }
+
+    #[cfg(any(feature = "signed", feature = "private"))]
+    #[track_caller]
+    pub fn secret_key(&mut self, secret: &[u8]) {
+        self.config.set_secret_key(unwrap_error!(
+            SecretKey::from_secret(secret),
+            "Error while setting secret key"
+        ));
+    }
+
+    #[cfg(any(feature = "signed", feature = "private"))]
+    #[track_caller]
+    pub fn previous_secret_key(&mut self, secret: &[u8]) {
+        unwrap_error!(
+            self.add_previous_secret_key(secret),
+            "Error while adding previous secret key"
+        );
+    }
+
+    pub fn manage<T: Send + Sync + 'static>(&mut self, data: Data<T>) {
+        self.config.set_state(data);
+    }
+
     pub fn route_exists(&self, method: &Method, path: &str) -> bool {
         for route in self.routes.read().unwrap().iter() {
             if route.method() == method && route.path().original_url() == path {
@@ -52,8 +156,182 @@ impl Serwer {
         false
     }
 
+    pub fn preserve_header_case(&mut self, preserve_header_case: bool) {
+        self.config.set_preserve_header_case(preserve_header_case);
+    }
+
+    pub fn max_body_size(&mut self, max_body_size: usize) {
+        self.config.set_max_body_size(max_body_size);
+    }
+
+    pub fn compression(&mut self, compression: CompressionConfig) {
+        self.config.set_compression(compression);
+    }
+
+    pub fn decompression(&mut self, decompression: DecompressionConfig) {
+        self.config.set_decompression(decompression);
+    }
+
+    pub fn etags(&mut self, etag_mode: ETagMode) {
+        self.config.set_etag_mode(etag_mode);
+    }
+
+    #[track_caller]
+    fn add_route<F>(
+        &mut self,
+        method: Method,
+        path: &'static str,
+        action: F,
+        required_states: Vec<(TypeId, &'static str)>,
+    ) where
+        F: Fn(Request, Response) -> Response + Send + Sync + 'static,
+    {
+        if self.route_exists(&method, path) {
+            custom_panic!("Route already exists")
+        }
+
+        let routes = Arc::clone(&self.routes);
+        let mut routes = unwrap_error!(routes.write(), "Failed to lock routes for write access");
+
+        routes.push(
+            unwrap_error!(
+                Route::new(method, path, action),
+                "Error while setting route"
+            )
+            .set_required_states(required_states),
+        );
+    }
+
+    #[cfg(any(feature = "signed", feature = "private"))]
+    fn add_previous_secret_key(&mut self, secret: &[u8]) -> Result<(), SerwerError> {
+        if self.config.secret_keys().is_empty() {
+            return Err(SerwerError::MissingSecretKey(String::from(
+                "secret_key must be set before previous_secret_key",
+            )));
+        }
+
+        self.config
+            .add_previous_secret_key(SecretKey::from_secret(secret)?);
+
+        Ok(())
+    }
+
+    fn add_redirect(
+        &mut self,
+        from: &str,
+        to: &str,
+        status_code: StatusCode,
+    ) -> Result<(), SerwerError> {
+        let from = Path::from_string(from)?;
+
+        if !status_code.is_redirect() {
+            return Err(SerwerError::InvalidRedirect(status_code.to_string()));
+        }
+
+        self.config.add_redirect(RedirectRule::Path {
+            from,
+            to: String::from(to),
+            status_code,
+        });
+
+        Ok(())
+    }
+
+    fn add_https_redirect(&mut self, host: &str, port: Option<u16>) -> Result<(), SerwerError> {
+        if RedirectRule::host_name(host) != Some(host) {
+            return Err(SerwerError::InvalidRedirect(String::from(host)));
+        }
+
+        self.config.add_redirect(RedirectRule::Https {
+            host: Some(String::from(host)),
+            port,
+        });
+
+        Ok(())
+    }
+
+    fn add_mount(&mut self, prefix: &str, static_files: StaticFiles) -> Result<(), SerwerError> {
+        let prefix = Path::from_string(prefix)?;
+
+        if prefix.contains_params() {
+            return Err(SerwerError::InvalidMount(format!(
+                "{} cannot contain params",
+                prefix.original_url()
+            )));
+        }
+
+        if self.config.mounts().iter().any(|(mount_prefix, _)| {
+            mount_prefix.original_url().trim_end_matches('/')
+                == prefix.original_url().trim_end_matches('/')
+        }) {
+            return Err(SerwerError::InvalidMount(format!(
+                "{} already exists",
+                prefix.original_url()
+            )));
+        }
+
+        if !static_files.directory().is_dir() {
+            return Err(SerwerError::InvalidMount(format!(
+                "{} does not exist",
+                static_files.directory().display()
+            )));
+        }
+
+        self.config.add_mount(prefix, static_files);
+
+        Ok(())
+    }
+
+    #[track_caller]
+    fn set_route_cache_control(
+        &mut self,
+        method: Method,
+        path: &str,
+        cache_control: CacheControl,
+    ) -> Result<(), SerwerError> {
+        let mut routes = unwrap_error!(self.routes.write(), "Error while writing routes");
+
+        match routes
+            .iter_mut()
+            .find(|route| route.method() == &method && route.path().original_url() == path)
+        {
+            Some(route) => {
+                route.set_cache_control(cache_control);
+                Ok(())
+            }
+            None => Err(SerwerError::MissingRoute(format!(
+                "{} {}",
+                method.to_string(),
+                path
+            ))),
+        }
+    }
+
+    #[track_caller]
+    fn check_states(&self) -> Result<(), SerwerError> {
+        for route in unwrap_error!(self.routes.read(), "Error while reading routes").iter() {
+            for (type_id, name) in route.required_states() {
+                if !self.config.states().contains(type_id) {
+                    return Err(SerwerError::MissingState(format!(
+                        "{} for route {} {}",
+                        name,
+                        route.method().to_string(),
+                        route.path().original_url()
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     #[track_caller]
     pub fn listen(&mut self, port: u16) {
+        unwrap_error!(
+            self.check_states(),
+            "Every state required by a route must be registered with manage"
+        );
+
         self.listener = Some(unwrap_error!(
             TcpListener::bind(format!("127.0.0.1:{port}")),
             "Error while binding to a port"
@@ -66,6 +344,7 @@ impl Serwer {
             )
             .get(),
             &self.routes,
+            &Arc::new(self.config.clone()),
         ));
 
         for stream in
@@ -99,6 +378,297 @@ mod tests {
         assert_eq!(serwer.routes.read().unwrap().len(), 1);
     }
 
+    #[test]
+    fn test_route() {
+        let mut serwer = Serwer::new();
+
+        serwer.route(Method::GET, "/", || "Hello World");
+        serwer.route(Method::POST, "/", |method: Method| method.to_string());
+
+        assert!(serwer.route_exists(&Method::GET, "/"));
+        assert!(serwer.route_exists(&Method::POST, "/"));
+        assert_eq!(serwer.routes.read().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_manage() {
+        let mut serwer = Serwer::new();
+
+        serwer.manage(Data::new(42u32));
+
+        assert_eq!(*serwer.config.states().state::<u32>().unwrap().read(), 42);
+
+        serwer.route(Method::GET, "/", |count: Data<u32>| {
+            count.read().to_string()
+        });
+        assert!(serwer.check_states().is_ok());
+    }
+
+    #[test]
+    fn test_check_states_missing_state() {
+        let mut serwer = Serwer::new();
+
+        serwer.manage(Data::new(42u32));
+        serwer.route(Method::GET, "/", |name: Data<String>| name.read().clone());
+
+        assert!(matches!(
+            serwer.check_states(),
+            Err(SerwerError::MissingState(error_string)) if error_string == "alloc::string::String for route GET /"
+        ));
+    }
+
+    #[test]
+    fn test_etags() {
+        let mut serwer = Serwer::new();
+
+        assert_eq!(serwer.config.etag_mode(), None);
+
+        serwer.etags(ETagMode::Strong);
+
+        assert_eq!(serwer.config.etag_mode(), Some(ETagMode::Strong));
+    }
+
+    #[test]
+    fn test_mount() {
+        let mut serwer = Serwer::new();
+        let directory = std::env::temp_dir();
+
+        serwer.mount("/static", StaticFiles::new(&directory.to_string_lossy()));
+
+        assert_eq!(serwer.config.mounts().len(), 1);
+        assert_eq!(serwer.config.mounts()[0].0.original_url(), "/static");
+    }
+
+    #[test]
+    fn test_mount_invalid() {
+        let mut serwer = Serwer::new();
+        let directory = std::env::temp_dir();
+
+        assert!(matches!(
+            serwer.add_mount("/static", StaticFiles::new("/serwer/missing/directory")),
+            Err(SerwerError::InvalidMount(error_string)) if error_string == "/serwer/missing/directory does not exist"
+        ));
+        assert!(matches!(
+            serwer.add_mount("/<file>", StaticFiles::new(&directory.to_string_lossy())),
+            Err(SerwerError::InvalidMount(error_string)) if error_string == "/<file> cannot contain params"
+        ));
+
+        serwer.mount("/static", StaticFiles::new(&directory.to_string_lossy()));
+
+        assert!(matches!(
+            serwer.add_mount("/static/", StaticFiles::new(&directory.to_string_lossy())),
+            Err(SerwerError::InvalidMount(error_string)) if error_string == "/static/ already exists"
+        ));
+        assert_eq!(serwer.config.mounts().len(), 1);
+    }
+
+    #[test]
+    fn test_cache() {
+        let mut serwer = Serwer::new();
+
+        assert!(serwer.config.cache().is_none());
+
+        serwer.cache(ResponseCache::new().set_max_entries(10));
+
+        assert_eq!(serwer.config.cache().unwrap().max_entries(), 10);
+    }
+
+    #[test]
+    fn test_cache_control() {
+        let mut serwer = Serwer::new();
+        serwer.get("/", |_, res| res);
+
+        serwer.cache_control(Method::GET, "/", CacheControl::new().set_no_cache(true));
+
+        assert_eq!(
+            serwer.routes.read().unwrap()[0].cache_control(),
+            Some(&CacheControl::new().set_no_cache(true))
+        );
+    }
+
+    #[test]
+    fn test_cache_control_missing_route() {
+        let mut serwer = Serwer::new();
+        serwer.get("/", |_, res| res);
+
+        assert!(matches!(
+            serwer.set_route_cache_control(Method::POST, "/", CacheControl::new()),
+            Err(SerwerError::MissingRoute(error_string)) if error_string == "POST /"
+        ));
+        assert_eq!(serwer.routes.read().unwrap()[0].cache_control(), None);
+    }
+
+    #[test]
+    fn test_redirect() {
+        let mut serwer = Serwer::new();
+
+        serwer.redirect("/old/<id>", "/new/<id>", StatusCode::MovedPermanently);
+        serwer.redirect_trailing_slash(TrailingSlash::Remove);
+        serwer.redirect_https(Some(8443));
+
+        assert_eq!(
+            serwer.config.redirects(),
+            &vec![
+                RedirectRule::Path {
+                    from: Path::from_string("/old/<id>").unwrap(),
+                    to: String::from("/new/<id>"),
+                    status_code: StatusCode::MovedPermanently,
+                },
+                RedirectRule::TrailingSlash(TrailingSlash::Remove),
+                RedirectRule::Https {
+                    host: None,
+                    port: Some(8443)
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_redirect_https_to() {
+        let mut serwer = Serwer::new();
+
+        serwer.redirect_https_to("www.example.com", None);
+
+        assert!(matches!(
+            serwer.add_https_redirect("example.com:443", None),
+            Err(SerwerError::InvalidRedirect(error_string)) if error_string == "example.com:443"
+        ));
+        assert!(matches!(
+            serwer.add_https_redirect("evil.com/x", None),
+            Err(SerwerError::InvalidRedirect(_))
+        ));
+        assert_eq!(
+            serwer.config.redirects(),
+            &vec![RedirectRule::Https {
+                host: Some(String::from("www.example.com")),
+                port: None
+            }]
+        );
+    }
+
+    #[test]
+    fn test_redirect_invalid() {
+        let mut serwer = Serwer::new();
+
+        assert!(matches!(
+            serwer.add_redirect("/old", "/new", StatusCode::OK),
+            Err(SerwerError::InvalidRedirect(error_string)) if error_string == "200 OK"
+        ));
+        assert!(matches!(
+            serwer.add_redirect("old", "/new", StatusCode::Found),
+            Err(SerwerError::PathMissingLeadingSlash(_))
+        ));
+        assert!(serwer.config.redirects().is_empty());
+    }
+
+    #[test]
+    fn test_normalization() {
+        let mut serwer = Serwer::new();
+
+        serwer.normalization(NormalizationConfig::new().set_case_folding(true));
+
+        assert!(serwer.config.normalization().case_folding());
+    }
+
+    #[test]
+    fn test_rewrite() {
+        let mut serwer = Serwer::new();
+
+        serwer.rewrite("/u/<id>", "/user/<id>");
+
+        assert_eq!(
+            serwer.config.rewrites(),
+            &vec![RewriteRule::new("/u/<id>", "/user/<id>").unwrap()]
+        );
+    }
+
+    #[test]
+    fn test_cookie_encoding() {
+        let mut serwer = Serwer::new();
+
+        serwer.cookie_encoding(CookieEncoding::Percent);
+
+        assert_eq!(serwer.config.cookie_encoding(), CookieEncoding::Percent);
+    }
+
+    #[cfg(any(feature = "signed", feature = "private"))]
+    #[test]
+    fn test_secret_key() {
+        let mut serwer = Serwer::new();
+
+        serwer.secret_key(&[1; 32]);
+        serwer.previous_secret_key(&[2; 64]);
+
+        assert_eq!(
+            serwer.config.secret_keys(),
+            &vec![
+                SecretKey::from_secret(&[1; 32]).unwrap(),
+                SecretKey::from_secret(&[2; 64]).unwrap()
+            ]
+        );
+    }
+
+    #[cfg(any(feature = "signed", feature = "private"))]
+    #[test]
+    fn test_previous_secret_key_invalid() {
+        let mut serwer = Serwer::new();
+
+        assert!(matches!(
+            serwer.add_previous_secret_key(&[2; 32]),
+            Err(SerwerError::MissingSecretKey(_))
+        ));
+
+        serwer.secret_key(&[1; 32]);
+
+        assert!(matches!(
+            serwer.add_previous_secret_key(b"secret"),
+            Err(SerwerError::InvalidSecretKey(error_string)) if &error_string == "6"
+        ));
+        assert_eq!(serwer.config.secret_keys().len(), 1);
+    }
+
+    #[test]
+    fn test_preserve_header_case() {
+        let mut serwer = Serwer::new();
+
+        assert!(!serwer.config.preserve_header_case());
+
+        serwer.preserve_header_case(true);
+
+        assert!(serwer.config.preserve_header_case());
+    }
+
+    #[test]
+    fn test_max_body_size() {
+        let mut serwer = Serwer::new();
+
+        serwer.max_body_size(1024);
+
+        assert_eq!(serwer.config.max_body_size(), 1024);
+    }
+
+    #[test]
+    fn test_compression() {
+        let mut serwer = Serwer::new();
+
+        assert_eq!(serwer.config.compression(), None);
+
+        serwer.compression(CompressionConfig::new().set_min_size(256));
+
+        assert_eq!(serwer.config.compression().unwrap().min_size(), 256);
+    }
+
+    #[test]
+    fn test_decompression() {
+        let mut serwer = Serwer::new();
+
+        assert_eq!(serwer.config.decompression(), None);
+
+        serwer.decompression(DecompressionConfig::new().set_max_size(1024));
+
+        assert_eq!(serwer.config.decompression().unwrap().max_size(), 1024);
+    }
+
     #[test]
     fn test_listen() {
         thread::spawn(|| {
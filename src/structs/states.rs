@@ -0,0 +1,104 @@
+use crate::Data;
+use std::{
+    any::{self, Any, TypeId},
+    collections::HashMap,
+    fmt,
+    sync::Arc,
+};
+
+#[derive(Clone, Default)]
+pub struct States {
+    states: HashMap<TypeId, (&'static str, Arc<dyn Any + Send + Sync>)>,
+}
+
+impl States {
+    pub fn new() -> Self {
+        Self {
+            states: HashMap::new(),
+        }
+    }
+
+    pub fn state<T: Send + Sync + 'static>(&self) -> Option<Data<T>> {
+        self.states
+            .get(&TypeId::of::<T>())
+            .and_then(|(_, state)| state.downcast_ref::<Data<T>>())
+            .cloned()
+    }
+
+    pub fn contains(&self, type_id: &TypeId) -> bool {
+        self.states.contains_key(type_id)
+    }
+
+    pub fn set_state<T: Send + Sync + 'static>(&mut self, data: Data<T>) {
+        self.states
+            .insert(TypeId::of::<T>(), (any::type_name::<T>(), Arc::new(data)));
+    }
+}
+
+impl fmt::Debug for States {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut names: Vec<&str> = self.states.values().map(|(name, _)| *name).collect();
+        names.sort();
+
+        f.debug_set().entries(names).finish()
+    }
+}
+
+impl PartialEq for States {
+    fn eq(&self, other: &Self) -> bool {
+        self.states.len() == other.states.len()
+            && self
+                .states
+                .iter()
+                .all(|(type_id, (_, state))| match other.states.get(type_id) {
+                    Some((_, other_state)) => Arc::ptr_eq(state, other_state),
+                    None => false,
+                })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_state() {
+        let mut states = States::new();
+        assert!(states.state::<u32>().is_none());
+
+        let data = Data::new(42u32);
+        states.set_state(data.clone());
+        states.set_state(Data::new(String::from("serwer")));
+
+        *data.write() = 43;
+
+        assert_eq!(*states.state::<u32>().unwrap().read(), 43);
+        assert_eq!(*states.state::<String>().unwrap().read(), "serwer");
+        assert!(states.state::<u64>().is_none());
+        assert!(states.contains(&TypeId::of::<u32>()));
+        assert!(!states.contains(&TypeId::of::<u64>()));
+    }
+
+    #[test]
+    fn test_debug() {
+        let mut states = States::new();
+        states.set_state(Data::new(42u32));
+        states.set_state(Data::new(true));
+
+        assert_eq!(format!("{:?}", states), r#"{"bool", "u32"}"#);
+    }
+
+    #[test]
+    fn test_eq() {
+        let mut states = States::new();
+        states.set_state(Data::new(42u32));
+
+        assert_eq!(states, states.clone());
+        assert_ne!(states, States::new());
+
+        let mut other_states = States::new();
+        other_states.set_state(Data::new(42u32));
+
+        assert_ne!(states, other_states);
+    }
+}
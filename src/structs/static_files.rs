@@ -0,0 +1,335 @@
+use crate::{
+    encode, escape_html, CacheControl, ContentType, MediaType, Request, Response, SerwerError,
+    StatusCode,
+};
+use std::{
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StaticFiles {
+    directory: PathBuf,
+    index_file: Option<String>,
+    directory_listing: bool,
+    cache_control: Option<CacheControl>,
+}
+
+impl StaticFiles {
+    pub fn new(directory: &str) -> Self {
+        Self {
+            directory: PathBuf::from(directory),
+            index_file: Some(String::from("index.html")),
+            directory_listing: false,
+            cache_control: None,
+        }
+    }
+
+    pub fn directory(&self) -> &PathBuf {
+        &self.directory
+    }
+
+    pub fn index_file(&self) -> Option<&String> {
+        self.index_file.as_ref()
+    }
+
+    pub fn directory_listing(&self) -> bool {
+        self.directory_listing
+    }
+
+    pub fn cache_control(&self) -> Option<&CacheControl> {
+        self.cache_control.as_ref()
+    }
+
+    pub fn set_index_file(mut self, index_file: Option<&str>) -> Self {
+        self.index_file = index_file.map(String::from);
+        self
+    }
+
+    pub fn set_directory_listing(mut self, directory_listing: bool) -> Self {
+        self.directory_listing = directory_listing;
+        self
+    }
+
+    pub fn set_cache_control(mut self, cache_control: CacheControl) -> Self {
+        self.cache_control = Some(cache_control);
+        self
+    }
+
+    pub(crate) fn serve(
+        &self,
+        segments: &[String],
+        request: &Request,
+        mut response: Response,
+    ) -> Response {
+        if segments.iter().any(|segment| !is_safe_segment(segment)) {
+            response.set_status_code(StatusCode::NotFound);
+            return response;
+        }
+
+        let path = segments
+            .iter()
+            .filter(|segment| !segment.is_empty())
+            .fold(self.directory.clone(), |path, segment| path.join(segment));
+        let trailing_slash = segments.last().is_some_and(String::is_empty);
+
+        let metadata = match fs::metadata(&path) {
+            Ok(metadata) => metadata,
+            Err(error) if error.kind() == ErrorKind::NotFound => {
+                response.set_status_code(StatusCode::NotFound);
+                return response;
+            }
+            Err(error) => {
+                response.set_error(&SerwerError::from(error));
+                return response;
+            }
+        };
+
+        if metadata.is_file() {
+            if trailing_slash {
+                response.set_status_code(StatusCode::NotFound);
+                return response;
+            }
+
+            return Self::serve_file(&path, response);
+        }
+
+        if !trailing_slash {
+            let url = request.original_url();
+            let location = match url.split_once('?') {
+                Some((path, query)) => format!("{}/?{}", path, query),
+                None => format!("{}/", url),
+            };
+
+            response.moved_permanently(&location);
+            return response;
+        }
+
+        if let Some(index_file) = &self.index_file {
+            let index_path = path.join(index_file);
+
+            if index_path.is_file() {
+                return Self::serve_file(&index_path, response);
+            }
+        }
+
+        if self.directory_listing {
+            return Self::serve_directory_listing(&path, segments.len() > 1, request, response);
+        }
+
+        response.set_status_code(StatusCode::NotFound);
+        response
+    }
+
+    fn serve_file(path: &Path, mut response: Response) -> Response {
+        response.file(path);
+        response
+    }
+
+    fn serve_directory_listing(
+        path: &Path,
+        has_parent: bool,
+        request: &Request,
+        mut response: Response,
+    ) -> Response {
+        let entries = match fs::read_dir(path) {
+            Ok(entries) => entries,
+            Err(error) => {
+                response.set_error(&SerwerError::from(error));
+                return response;
+            }
+        };
+
+        let mut names: Vec<String> = entries
+            .filter_map(Result::ok)
+            .map(|entry| {
+                let name = entry.file_name().to_string_lossy().to_string();
+
+                if entry.path().is_dir() {
+                    format!("{}/", name)
+                } else {
+                    name
+                }
+            })
+            .filter(|name| !name.starts_with('.'))
+            .collect();
+        names.sort();
+
+        if has_parent {
+            names.insert(0, String::from("../"));
+        }
+
+        let url = request.original_url();
+        let title = escape_html(url.split('?').next().unwrap_or_default());
+        let items: String = names
+            .iter()
+            .map(|name| {
+                let href = match name.strip_suffix('/') {
+                    Some(name) => format!("{}/", encode(name)),
+                    None => encode(name),
+                };
+
+                format!("<li><a href=\"{}\">{}</a></li>\n", href, escape_html(name))
+            })
+            .collect();
+
+        let body = format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Index of {}</title>\n</head>\n<body>\n<h1>Index of {}</h1>\n<ul>\n{}</ul>\n</body>\n</html>\n",
+            title, title, items
+        );
+
+        response.set_typed_header(ContentType(
+            MediaType::new("text", "html").set_param("charset", "utf-8"),
+        ));
+        response.set_body_from_bytes(body.into_bytes());
+        response
+    }
+}
+
+fn is_safe_segment(segment: &str) -> bool {
+    !segment.starts_with('.') && !segment.contains(['/', '\\', ':', '\0'])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{request_from_bytes, Version};
+    use std::env;
+
+    fn directory(name: &str) -> PathBuf {
+        let directory = env::temp_dir().join(format!("serwer_static_files_{}", name));
+        let _ = fs::remove_dir_all(&directory);
+
+        fs::create_dir_all(directory.join("css")).unwrap();
+        fs::create_dir_all(directory.join("docs")).unwrap();
+        fs::write(directory.join("index.html"), "<h1>Home</h1>").unwrap();
+        fs::write(directory.join("css").join("main.css"), "body {}").unwrap();
+        fs::write(directory.join("docs").join("a & b.txt"), "Hello").unwrap();
+        fs::write(directory.join(".env"), "SECRET=1").unwrap();
+
+        directory
+    }
+
+    fn serve(static_files: &StaticFiles, prefix: &str, request: &[u8]) -> String {
+        let request = request_from_bytes(request).unwrap();
+        let segments = request
+            .path()
+            .strip_prefix(&crate::Path::from_string(prefix).unwrap())
+            .unwrap();
+        let mut response =
+            static_files.serve(&segments, &request, Response::new(&Version::HTTP_1_1));
+        response.remove_header("Last-Modified");
+
+        String::from_utf8(response.write()).unwrap()
+    }
+
+    #[test]
+    fn test_static_files_builder() {
+        let static_files = StaticFiles::new("static")
+            .set_index_file(None)
+            .set_directory_listing(true)
+            .set_cache_control(CacheControl::new().set_no_cache(true));
+
+        assert_eq!(static_files.directory(), &PathBuf::from("static"));
+        assert_eq!(static_files.index_file(), None);
+        assert!(static_files.directory_listing());
+        assert_eq!(
+            static_files.cache_control(),
+            Some(&CacheControl::new().set_no_cache(true))
+        );
+    }
+
+    #[test]
+    fn test_serve_file() {
+        let directory = directory("file");
+        let static_files = StaticFiles::new(&directory.to_string_lossy());
+
+        assert_eq!(
+            serve(&static_files, "/static", b"GET /static/css/main.css HTTP/1.1\r\n\r\n"),
+            "HTTP/1.1 200 OK\r\naccept-ranges: bytes\r\ncontent-length: 7\r\ncontent-type: text/css; charset=utf-8\r\n\r\nbody {}"
+        );
+        assert_eq!(
+            serve(
+                &static_files,
+                "/static",
+                b"GET /static/css/main.css/ HTTP/1.1\r\n\r\n"
+            ),
+            "HTTP/1.1 404 Not Found\r\n\r\n"
+        );
+        assert_eq!(
+            serve(
+                &static_files,
+                "/static",
+                b"GET /static/missing.css HTTP/1.1\r\n\r\n"
+            ),
+            "HTTP/1.1 404 Not Found\r\n\r\n"
+        );
+    }
+
+    #[test]
+    fn test_serve_index() {
+        let directory = directory("index");
+        let static_files = StaticFiles::new(&directory.to_string_lossy());
+
+        assert_eq!(
+            serve(&static_files, "/", b"GET / HTTP/1.1\r\n\r\n"),
+            "HTTP/1.1 200 OK\r\naccept-ranges: bytes\r\ncontent-length: 13\r\ncontent-type: text/html; charset=utf-8\r\n\r\n<h1>Home</h1>"
+        );
+        assert_eq!(
+            serve(
+                &static_files,
+                "/static",
+                b"GET /static?a=1 HTTP/1.1\r\n\r\n"
+            ),
+            "HTTP/1.1 301 Moved Permanently\r\nlocation: /static/?a=1\r\n\r\n"
+        );
+        assert_eq!(
+            serve(&static_files, "/", b"GET /css/ HTTP/1.1\r\n\r\n"),
+            "HTTP/1.1 404 Not Found\r\n\r\n"
+        );
+    }
+
+    #[test]
+    fn test_serve_directory_listing() {
+        let directory = directory("listing");
+        let static_files = StaticFiles::new(&directory.to_string_lossy())
+            .set_index_file(None)
+            .set_directory_listing(true);
+
+        let response = serve(&static_files, "/", b"GET / HTTP/1.1\r\n\r\n");
+        assert!(response.contains("<title>Index of /</title>"));
+        assert!(response.contains("<li><a href=\"css/\">css/</a></li>\n<li><a href=\"docs/\">docs/</a></li>\n<li><a href=\"index.html\">index.html</a></li>\n</ul>"));
+        assert!(!response.contains(".env"));
+        assert!(!response.contains("../"));
+
+        let response = serve(&static_files, "/", b"GET /docs/ HTTP/1.1\r\n\r\n");
+        assert!(response.contains("<li><a href=\"../\">../</a></li>\n<li><a href=\"a%20%26%20b.txt\">a &amp; b.txt</a></li>\n</ul>"));
+    }
+
+    #[test]
+    fn test_serve_rejects_traversal_and_dotfiles() {
+        let directory = directory("traversal");
+        let static_files = StaticFiles::new(&directory.join("css").to_string_lossy());
+
+        assert_eq!(
+            serve(&static_files, "/", b"GET /../index.html HTTP/1.1\r\n\r\n"),
+            "HTTP/1.1 404 Not Found\r\n\r\n"
+        );
+        assert_eq!(
+            serve(
+                &static_files,
+                "/",
+                b"GET /%2E%2E/index.html HTTP/1.1\r\n\r\n"
+            ),
+            "HTTP/1.1 404 Not Found\r\n\r\n"
+        );
+
+        let static_files = StaticFiles::new(&directory.to_string_lossy());
+
+        assert_eq!(
+            serve(&static_files, "/", b"GET /.env HTTP/1.1\r\n\r\n"),
+            "HTTP/1.1 404 Not Found\r\n\r\n"
+        );
+    }
+}
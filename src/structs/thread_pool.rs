@@ -1,4 +1,4 @@
-use crate::{utils::macros::unwrap_error, Route, Worker};
+use crate::{utils::macros::unwrap_error, Config, Route, Worker};
 use std::{
     net::TcpStream,
     sync::{mpsc, Arc, Mutex, RwLock},
@@ -11,14 +11,19 @@ pub struct ThreadPool {
 }
 
 impl ThreadPool {
-    pub fn new(size: usize, routes: &Arc<RwLock<Vec<Route>>>) -> Self {
+    pub fn new(size: usize, routes: &Arc<RwLock<Vec<Route>>>, config: &Arc<Config>) -> Self {
         let (sender, receiver) = mpsc::channel();
         let receiver = Arc::new(Mutex::new(receiver));
 
         let mut workers = Vec::with_capacity(size);
 
         for id in 0..size {
-            workers.push(Worker::new(id, Arc::clone(&receiver), Arc::clone(&routes)));
+            workers.push(Worker::new(
+                id,
+                Arc::clone(&receiver),
+                Arc::clone(&routes),
+                Arc::clone(config),
+            ));
         }
 
         Self {
@@ -41,7 +46,7 @@ mod tests {
     #[test]
     fn test_new() {
         let routes = Arc::new(RwLock::new(vec![]));
-        let pool = ThreadPool::new(4, &routes);
+        let pool = ThreadPool::new(4, &routes, &Arc::new(Config::new()));
         assert_eq!(pool._workers.len(), 4);
     }
 
@@ -57,7 +62,7 @@ mod tests {
         })
         .unwrap();
         let routes = Arc::new(RwLock::new(vec![route]));
-        let pool = ThreadPool::new(4, &routes);
+        let pool = ThreadPool::new(4, &routes, &Arc::new(Config::new()));
 
         let stream = stream_from_bytes(b"GET / HTTP/1.1\r\n\r\n");
         pool.handle_stream(stream);
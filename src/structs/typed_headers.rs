@@ -0,0 +1,251 @@
+use crate::{
+    is_token,
+    utils::macros::{generate_quality_header, generate_typed_header},
+    ByteRange, Credentials, EntityTag, EntityTagList, HttpDate, MediaType, QualityItem,
+    RangeCondition, SerwerError, TypedHeader,
+};
+use std::time::Duration;
+
+generate_typed_header!(ContentType, "Content-Type", MediaType);
+generate_typed_header!(Authorization, "Authorization", Credentials);
+generate_typed_header!(ETag, "ETag", EntityTag);
+generate_typed_header!(IfMatch, "If-Match", EntityTagList);
+generate_typed_header!(IfNoneMatch, "If-None-Match", EntityTagList);
+generate_typed_header!(Date, "Date", HttpDate);
+generate_typed_header!(Expires, "Expires", HttpDate);
+generate_typed_header!(LastModified, "Last-Modified", HttpDate);
+generate_typed_header!(IfModifiedSince, "If-Modified-Since", HttpDate);
+generate_typed_header!(IfUnmodifiedSince, "If-Unmodified-Since", HttpDate);
+generate_typed_header!(IfRange, "If-Range", RangeCondition);
+
+generate_quality_header!(Accept, "Accept", MediaType, MediaType::from_string);
+generate_quality_header!(AcceptLanguage, "Accept-Language", String, parse_token);
+generate_quality_header!(AcceptEncoding, "Accept-Encoding", String, parse_token);
+generate_quality_header!(AcceptCharset, "Accept-Charset", String, parse_token);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Range(pub Vec<ByteRange>);
+
+impl TypedHeader for Range {
+    fn name() -> &'static str {
+        "Range"
+    }
+
+    fn from_value(value: &str) -> Result<Self, SerwerError> {
+        let ranges = value
+            .trim()
+            .strip_prefix("bytes=")
+            .ok_or(SerwerError::InvalidHeaderValue(String::from(value)))?;
+
+        let mut byte_ranges = vec![];
+
+        for range in ranges.split(',') {
+            if !range.trim().is_empty() {
+                byte_ranges.push(ByteRange::from_string(range)?);
+            }
+        }
+
+        if byte_ranges.is_empty() {
+            return Err(SerwerError::InvalidHeaderValue(String::from(value)));
+        }
+
+        Ok(Self(byte_ranges))
+    }
+
+    fn to_value(&self) -> String {
+        format!(
+            "bytes={}",
+            self.0
+                .iter()
+                .map(|range| range.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Age(pub Duration);
+
+impl TypedHeader for Age {
+    fn name() -> &'static str {
+        "Age"
+    }
+
+    fn from_value(value: &str) -> Result<Self, SerwerError> {
+        let seconds = value.trim();
+
+        if seconds.is_empty() || !seconds.chars().all(|c| c.is_ascii_digit()) {
+            return Err(SerwerError::InvalidHeaderValue(String::from(value)));
+        }
+
+        Ok(Self(Duration::from_secs(seconds.parse()?)))
+    }
+
+    fn to_value(&self) -> String {
+        self.0.as_secs().to_string()
+    }
+}
+
+fn parse_token(string: &str) -> Result<String, SerwerError> {
+    let token = string.trim();
+
+    if !is_token(token) {
+        return Err(SerwerError::InvalidHeaderValue(String::from(string)));
+    }
+
+    Ok(String::from(token))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip<T: TypedHeader + PartialEq + std::fmt::Debug>(value: &str, expected: T) {
+        let header = T::from_value(value).unwrap();
+        assert_eq!(header, expected);
+        assert_eq!(T::from_value(&header.to_value()).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_content_type() {
+        assert_eq!(ContentType::name(), "Content-Type");
+        round_trip(
+            "application/json; charset=utf-8",
+            ContentType(MediaType::new("application", "json").set_param("charset", "utf-8")),
+        );
+    }
+
+    #[test]
+    fn test_accept() {
+        round_trip(
+            "text/html, application/json;q=0.9, */*;q=0.1",
+            Accept(vec![
+                QualityItem::new(MediaType::new("text", "html"), 1000),
+                QualityItem::new(MediaType::new("application", "json"), 900),
+                QualityItem::new(MediaType::new("*", "*"), 100),
+            ]),
+        );
+        assert_eq!(
+            Accept::from_value("text/html;q=0.5").unwrap().to_value(),
+            "text/html; q=0.5"
+        );
+    }
+
+    #[test]
+    fn test_accept_language() {
+        round_trip(
+            "en-US, en;q=0.8, *;q=0.1",
+            AcceptLanguage(vec![
+                QualityItem::new(String::from("en-US"), 1000),
+                QualityItem::new(String::from("en"), 800),
+                QualityItem::new(String::from("*"), 100),
+            ]),
+        );
+        assert!(matches!(
+            AcceptLanguage::from_value("en, (fr)"),
+            Err(SerwerError::InvalidHeaderValue(error_string)) if &error_string == "(fr)"
+        ));
+    }
+
+    #[test]
+    fn test_accept_encoding() {
+        round_trip(
+            "gzip, identity;q=0.5, *;q=0",
+            AcceptEncoding(vec![
+                QualityItem::new(String::from("gzip"), 1000),
+                QualityItem::new(String::from("identity"), 500),
+                QualityItem::new(String::from("*"), 0),
+            ]),
+        );
+        round_trip("", AcceptEncoding(vec![]));
+    }
+
+    #[test]
+    fn test_accept_charset() {
+        round_trip(
+            "utf-8, iso-8859-1;q=0.5",
+            AcceptCharset(vec![
+                QualityItem::new(String::from("utf-8"), 1000),
+                QualityItem::new(String::from("iso-8859-1"), 500),
+            ]),
+        );
+    }
+
+    #[test]
+    fn test_authorization() {
+        round_trip(
+            "Bearer token",
+            Authorization(Credentials::Bearer(String::from("token"))),
+        );
+    }
+
+    #[test]
+    fn test_entity_tags() {
+        round_trip("W/\"1\"", ETag(EntityTag::weak("1")));
+        round_trip("*", IfMatch(EntityTagList::Any));
+        round_trip(
+            "\"1\", \"2\"",
+            IfNoneMatch(EntityTagList::Tags(vec![
+                EntityTag::strong("1"),
+                EntityTag::strong("2"),
+            ])),
+        );
+    }
+
+    #[test]
+    fn test_dates() {
+        let date = HttpDate::from_unix_timestamp(784111777);
+
+        round_trip("Sun, 06 Nov 1994 08:49:37 GMT", LastModified(date));
+        round_trip("Sun, 06 Nov 1994 08:49:37 GMT", IfModifiedSince(date));
+        assert_eq!(Expires(date).to_value(), "Sun, 06 Nov 1994 08:49:37 GMT");
+        assert!(matches!(
+            Date::from_value("yesterday"),
+            Err(SerwerError::InvalidDate(error_string)) if &error_string == "yesterday"
+        ));
+    }
+
+    #[test]
+    fn test_range() {
+        round_trip(
+            "bytes=0-499, 1000-, -200",
+            Range(vec![
+                ByteRange::FromTo(0, 499),
+                ByteRange::From(1000),
+                ByteRange::Last(200),
+            ]),
+        );
+        assert!(matches!(
+            Range::from_value("items=0-1"),
+            Err(SerwerError::InvalidHeaderValue(error_string)) if &error_string == "items=0-1"
+        ));
+        assert!(matches!(
+            Range::from_value("bytes="),
+            Err(SerwerError::InvalidHeaderValue(error_string)) if &error_string == "bytes="
+        ));
+    }
+
+    #[test]
+    fn test_if_range() {
+        round_trip(
+            "\"1\"",
+            IfRange(RangeCondition::EntityTag(EntityTag::strong("1"))),
+        );
+        round_trip(
+            "Sun, 06 Nov 1994 08:49:37 GMT",
+            IfRange(RangeCondition::Date(HttpDate::from_unix_timestamp(
+                784111777,
+            ))),
+        );
+    }
+
+    #[test]
+    fn test_age() {
+        round_trip("60", Age(Duration::from_secs(60)));
+        assert!(matches!(
+            Age::from_value("-1"),
+            Err(SerwerError::InvalidHeaderValue(error_string)) if &error_string == "-1"
+        ));
+    }
+}
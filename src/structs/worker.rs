@@ -1,9 +1,8 @@
 use crate::{
     utils::macros::{print_error, unwrap_error},
-    Method, Request, Response, Route, StatusCode, Version,
+    Config, Method, Request, Response, Route, SerwerError, StatusCode, Version,
 };
 use std::{
-    io::Write,
     net::TcpStream,
     sync::{mpsc, Arc, Mutex, RwLock},
     thread,
@@ -20,6 +19,7 @@ impl Worker {
         id: usize,
         receiver: Arc<Mutex<mpsc::Receiver<TcpStream>>>,
         routes: Arc<RwLock<Vec<Route>>>,
+        config: Arc<Config>,
     ) -> Self {
         let thread = thread::spawn(move || loop {
             let mut stream = unwrap_error!(
@@ -27,10 +27,10 @@ impl Worker {
                 "Failed to receive stream from receiver"
             );
 
-            let response = Self::handle_stream(&stream, &routes);
+            let response = Self::handle_stream(&stream, &routes, &config);
 
             print_error!(
-                stream.write_all(response.write().as_slice()),
+                response.write_to(&mut stream),
                 "Error while writing response"
             );
         });
@@ -41,35 +41,146 @@ impl Worker {
         }
     }
 
-    fn handle_stream(stream: &TcpStream, routes: &Arc<RwLock<Vec<Route>>>) -> Response {
-        let request = Request::from_stream(&stream);
+    fn handle_stream(
+        stream: &TcpStream,
+        routes: &Arc<RwLock<Vec<Route>>>,
+        config: &Config,
+    ) -> Response {
+        let request = Request::from_stream(&stream, config.max_body_size());
 
-        if let Ok(request) = request {
-            for route in unwrap_error!(routes.read(), "Error while reading routes").iter() {
-                if route.method() == &request.method() || route.method() == &Method::ALL {
-                    let (matches, params) = route.path().matches(&request.path());
+        let mut response = if let Ok(mut request) = request {
+            let prepared = match config.decompression() {
+                Some(decompression) => request.decompress(decompression),
+                None => Ok(()),
+            }
+            .and_then(|()| request.rewrite(config.normalization(), config.rewrites()));
 
-                    if matches {
-                        let mut request = request.clone();
-                        request.set_params(params.unwrap());
+            let mut response = match prepared {
+                Ok(()) => match config.cache() {
+                    Some(cache) => cache.get(&request).unwrap_or_else(|| {
+                        let response = Self::route_request(&request, routes, config);
+                        cache.store(&request, &response);
 
-                        return route.run_action(request);
-                    }
+                        response
+                    }),
+                    None => Self::route_request(&request, routes, config),
+                },
+                Err(error) => {
+                    let mut response = Response::new(&Version::HTTP_1_1);
+                    response.set_error(&error);
+
+                    response
                 }
+            };
+
+            if let Some(etag_mode) = config.etag_mode() {
+                response.generate_etag(etag_mode);
             }
 
-            let mut response = Response::new(&Version::HTTP_1_1);
-            response.set_status_code(StatusCode::NotFound);
+            if matches!(request.method(), Method::GET | Method::HEAD) {
+                response.apply_conditionals(&request);
+            }
+
+            if let Some(compression) = config.compression() {
+                response.compress(&request, compression);
+            }
+
+            if request.method() == Method::HEAD {
+                response.clear_body();
+            }
 
             response
         } else {
+            let status_code = match &request {
+                Err(SerwerError::BodyTooLarge(_)) => StatusCode::PayloadTooLarge,
+                _ => StatusCode::BadRequest,
+            };
+
             print_error!(request, "Error while reading request");
 
             let mut response = Response::new(&Version::HTTP_1_1);
-            response.set_status_code(StatusCode::BadRequest);
+            response.set_status_code(status_code);
 
             response
+        };
+
+        if config.preserve_header_case() {
+            response.set_preserve_header_case(true);
         }
+
+        response
+    }
+
+    fn route_request(
+        request: &Request,
+        routes: &Arc<RwLock<Vec<Route>>>,
+        config: &Config,
+    ) -> Response {
+        let routes = unwrap_error!(routes.read(), "Error while reading routes");
+
+        for redirect in config.redirects() {
+            if let Some((status_code, location)) = redirect.location(request, &routes) {
+                let mut response = Response::new(&Version::HTTP_1_1);
+                response.redirect(status_code, &location);
+
+                return response;
+            }
+        }
+
+        for route in routes.iter() {
+            if route.method() == &request.method() || route.method() == &Method::ALL {
+                let (matches, params) = route.path().matches(&request.path());
+
+                if matches {
+                    let mut request = request.clone();
+                    request.set_params(params.unwrap());
+                    request.set_states(config.states().clone());
+                    request.set_cookie_encoding(config.cookie_encoding());
+                    #[cfg(any(feature = "signed", feature = "private"))]
+                    request.set_secret_keys(config.secret_keys().clone());
+
+                    let mut response = route.run_action(request);
+                    response.set_default_cookie_encoding(config.cookie_encoding());
+                    #[cfg(any(feature = "signed", feature = "private"))]
+                    response.seal_cookies(config.secret_keys());
+
+                    if let Some(cache_control) = route.cache_control() {
+                        response.set_default_cache_control(cache_control);
+                    }
+
+                    return response;
+                }
+            }
+        }
+
+        if matches!(request.method(), Method::GET | Method::HEAD) {
+            let mount = config
+                .mounts()
+                .iter()
+                .filter_map(|(prefix, static_files)| {
+                    request
+                        .path()
+                        .strip_prefix(prefix)
+                        .map(|segments| (static_files, segments))
+                })
+                .min_by_key(|(_, segments)| segments.len());
+
+            if let Some((static_files, segments)) = mount {
+                let mut response =
+                    static_files.serve(&segments, request, Response::new(&Version::HTTP_1_1));
+
+                if let Some(cache_control) = static_files.cache_control() {
+                    response.set_default_cache_control(cache_control);
+                }
+
+                return response;
+            }
+        }
+
+        let mut response = Response::new(&Version::HTTP_1_1);
+        response.set_status_code(StatusCode::NotFound);
+
+        response
     }
 }
 
@@ -77,6 +188,15 @@ impl Worker {
 mod tests {
     use super::*;
     use crate::stream_from_bytes;
+    #[cfg(all(feature = "signed", feature = "private"))]
+    use crate::SecretKey;
+    use crate::{
+        CacheControl, Cookie, CookieEncoding, DecompressionConfig, ETagMode, NormalizationConfig,
+        Path, RedirectRule, ResponseCache, RewriteRule, StaticFiles, TrailingSlash,
+    };
+    #[cfg(feature = "gzip")]
+    use crate::{CompressionConfig, ContentEncoding};
+    use std::time::Duration;
 
     #[test]
     fn test_new() {
@@ -84,7 +204,7 @@ mod tests {
         let (_, receiver) = mpsc::channel();
         let receiver = Arc::new(Mutex::new(receiver));
 
-        let worker = Worker::new(0, receiver, routes);
+        let worker = Worker::new(0, receiver, routes, Arc::new(Config::new()));
         assert_eq!(worker._id, 0);
     }
 
@@ -98,7 +218,7 @@ mod tests {
         let routes = Arc::new(RwLock::new(vec![route]));
 
         let stream = stream_from_bytes(b"GET / HTTP/1.1\r\n\r\n");
-        let response = Worker::handle_stream(&stream, &routes);
+        let response = Worker::handle_stream(&stream, &routes, &Config::new());
 
         assert_eq!(
             String::from_utf8_lossy(response.write().as_slice()),
@@ -116,7 +236,7 @@ mod tests {
         let routes = Arc::new(RwLock::new(vec![route]));
 
         let stream = stream_from_bytes(b"GET\r\n\r\n");
-        let response = Worker::handle_stream(&stream, &routes);
+        let response = Worker::handle_stream(&stream, &routes, &Config::new());
 
         assert_eq!(
             String::from_utf8_lossy(response.write().as_slice()),
@@ -124,6 +244,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_handle_stream_body_too_large() {
+        let route = Route::new(Method::POST, "/", move |req, mut res| {
+            res.set_body(&req.body().unwrap());
+            res
+        })
+        .unwrap();
+        let routes = Arc::new(RwLock::new(vec![route]));
+        let mut config = Config::new();
+        config.set_max_body_size(4);
+
+        let stream = stream_from_bytes(b"POST / HTTP/1.1\r\nContent-Length: 4\r\n\r\nabcd");
+        let response = Worker::handle_stream(&stream, &routes, &config);
+        assert_eq!(response.status_code(), StatusCode::OK);
+
+        let stream = stream_from_bytes(b"POST / HTTP/1.1\r\nContent-Length: 1073741824\r\n\r\n");
+        let response = Worker::handle_stream(&stream, &routes, &config);
+        assert_eq!(
+            String::from_utf8_lossy(response.write().as_slice()),
+            "HTTP/1.1 413 Payload Too Large\r\n\r\n"
+        );
+    }
+
     #[test]
     fn test_handle_stream_not_found() {
         let route = Route::new(Method::GET, "/hello", move |_, mut res| {
@@ -134,11 +277,453 @@ mod tests {
         let routes = Arc::new(RwLock::new(vec![route]));
 
         let stream = stream_from_bytes(b"GET / HTTP/1.1\r\n\r\n");
-        let response = Worker::handle_stream(&stream, &routes);
+        let response = Worker::handle_stream(&stream, &routes, &Config::new());
+
+        assert_eq!(
+            String::from_utf8_lossy(response.write().as_slice()),
+            "HTTP/1.1 404 Not Found\r\n\r\n"
+        );
+    }
+
+    #[test]
+    fn test_handle_stream_preserve_header_case() {
+        let route = Route::new(Method::GET, "/", move |_, mut res| {
+            res.set(StatusCode::OK, "Hello World".to_string());
+            res
+        })
+        .unwrap();
+        let routes = Arc::new(RwLock::new(vec![route]));
+        let mut config = Config::new();
+        config.set_preserve_header_case(true);
+
+        let stream = stream_from_bytes(b"GET / HTTP/1.1\r\n\r\n");
+        let response = Worker::handle_stream(&stream, &routes, &config);
 
         assert_eq!(
             String::from_utf8_lossy(response.write().as_slice()),
+            "HTTP/1.1 200 OK\r\nContent-Length: 11\r\n\r\nHello World"
+        );
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn test_handle_stream_compression() {
+        let route = Route::new(Method::GET, "/", move |_, mut res| {
+            res.set_header("Content-Type", "text/plain");
+            res.set(StatusCode::OK, "a".repeat(2048));
+            res
+        })
+        .unwrap();
+        let routes = Arc::new(RwLock::new(vec![route]));
+        let mut config = Config::new();
+        config.set_compression(CompressionConfig::new());
+
+        let stream = stream_from_bytes(b"GET / HTTP/1.1\r\nAccept-Encoding: gzip\r\n\r\n");
+        let response = String::from_utf8_lossy(
+            Worker::handle_stream(&stream, &routes, &config)
+                .write()
+                .as_slice(),
+        )
+        .to_string();
+
+        assert!(response.contains("content-encoding: gzip\r\n"));
+        assert!(response.contains("vary: Accept-Encoding\r\n"));
+        assert!(!response.contains("content-length: 2048\r\n"));
+    }
+
+    #[test]
+    fn test_handle_stream_unsupported_encoding() {
+        let route = Route::new(Method::POST, "/", move |req, mut res| {
+            res.set(StatusCode::OK, req.body().unwrap());
+            res
+        })
+        .unwrap();
+        let routes = Arc::new(RwLock::new(vec![route]));
+        let mut config = Config::new();
+        config.set_decompression(DecompressionConfig::new());
+
+        let stream = stream_from_bytes(
+            b"POST / HTTP/1.1\r\nContent-Encoding: compress\r\nContent-Length: 5\r\n\r\nHello",
+        );
+        let response = Worker::handle_stream(&stream, &routes, &config);
+
+        assert_eq!(
+            String::from_utf8_lossy(response.write().as_slice()),
+            "HTTP/1.1 415 Unsupported Media Type\r\ncontent-length: 32\r\n\r\nUnsupported encoding: \"compress\""
+        );
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn test_handle_stream_decompression() {
+        let route = Route::new(Method::POST, "/", move |req, mut res| {
+            res.set(StatusCode::OK, req.body().unwrap());
+            res
+        })
+        .unwrap();
+        let routes = Arc::new(RwLock::new(vec![route]));
+        let mut config = Config::new();
+        config.set_decompression(DecompressionConfig::new().set_max_size(11));
+
+        let body = ContentEncoding::Gzip.encode(b"Hello World").unwrap();
+        let mut request = format!(
+            "POST / HTTP/1.1\r\nContent-Encoding: gzip\r\nContent-Length: {}\r\n\r\n",
+            body.len()
+        )
+        .into_bytes();
+        request.extend(&body);
+
+        let response = Worker::handle_stream(&stream_from_bytes(&request), &routes, &config);
+
+        assert_eq!(
+            String::from_utf8_lossy(response.write().as_slice()),
+            "HTTP/1.1 200 OK\r\ncontent-length: 11\r\n\r\nHello World"
+        );
+
+        config.set_decompression(DecompressionConfig::new().set_max_size(10));
+        let response = Worker::handle_stream(&stream_from_bytes(&request), &routes, &config);
+
+        assert_eq!(
+            String::from_utf8_lossy(response.write().as_slice()),
+            "HTTP/1.1 413 Payload Too Large\r\ncontent-length: 20\r\n\r\nBody too large: \"10\""
+        );
+    }
+
+    #[test]
+    fn test_handle_stream_mount() {
+        let route = Route::new(Method::GET, "/static/api", move |_, mut res| {
+            res.set(StatusCode::OK, "Hello World".to_string());
+            res
+        })
+        .unwrap();
+        let routes = Arc::new(RwLock::new(vec![route]));
+        let directory = std::env::temp_dir().join("serwer_worker_mount");
+        std::fs::create_dir_all(directory.join("nested")).unwrap();
+        std::fs::write(directory.join("hello.txt"), "Hello").unwrap();
+        std::fs::write(directory.join("nested").join("hello.txt"), "Nested").unwrap();
+
+        let mut config = Config::new();
+        config.add_mount(
+            Path::from_string("/static").unwrap(),
+            StaticFiles::new(&directory.to_string_lossy()),
+        );
+        config.add_mount(
+            Path::from_string("/static/hello").unwrap(),
+            StaticFiles::new(&directory.join("nested").to_string_lossy()),
+        );
+
+        let write = |request: &[u8]| {
+            let mut response = Worker::handle_stream(&stream_from_bytes(request), &routes, &config);
+            response.remove_header("Last-Modified");
+
+            String::from_utf8_lossy(response.write().as_slice()).to_string()
+        };
+
+        assert_eq!(
+            write(b"GET /static/api HTTP/1.1\r\n\r\n"),
+            "HTTP/1.1 200 OK\r\ncontent-length: 11\r\n\r\nHello World"
+        );
+        assert_eq!(
+            write(b"GET /static/hello.txt HTTP/1.1\r\n\r\n"),
+            "HTTP/1.1 200 OK\r\naccept-ranges: bytes\r\ncontent-length: 5\r\ncontent-type: text/plain; charset=utf-8\r\n\r\nHello"
+        );
+        assert_eq!(
+            write(b"GET /static/hello/hello.txt HTTP/1.1\r\n\r\n"),
+            "HTTP/1.1 200 OK\r\naccept-ranges: bytes\r\ncontent-length: 6\r\ncontent-type: text/plain; charset=utf-8\r\n\r\nNested"
+        );
+        assert_eq!(
+            write(b"HEAD /static/hello.txt HTTP/1.1\r\n\r\n"),
+            "HTTP/1.1 200 OK\r\naccept-ranges: bytes\r\ncontent-length: 5\r\ncontent-type: text/plain; charset=utf-8\r\n\r\n"
+        );
+        assert_eq!(
+            write(b"GET /static/hello.txt HTTP/1.1\r\nRange: bytes=1-3\r\n\r\n"),
+            "HTTP/1.1 206 Partial Content\r\naccept-ranges: bytes\r\ncontent-length: 3\r\ncontent-range: bytes 1-3/5\r\ncontent-type: text/plain; charset=utf-8\r\n\r\nell"
+        );
+        assert_eq!(
+            write(b"POST /static/hello.txt HTTP/1.1\r\n\r\n"),
             "HTTP/1.1 404 Not Found\r\n\r\n"
         );
     }
+
+    #[test]
+    fn test_handle_stream_etags() {
+        let route = Route::new(Method::GET, "/", move |_, mut res| {
+            res.set(StatusCode::OK, "Hello World".to_string());
+            res
+        })
+        .unwrap();
+        let routes = Arc::new(RwLock::new(vec![route]));
+        let mut config = Config::new();
+        config.set_etag_mode(ETagMode::Strong);
+
+        let stream = stream_from_bytes(b"GET / HTTP/1.1\r\n\r\n");
+        let response = Worker::handle_stream(&stream, &routes, &config);
+        let etag = response.header("ETag").unwrap();
+
+        let request = format!("GET / HTTP/1.1\r\nIf-None-Match: {}\r\n\r\n", etag);
+        let stream = stream_from_bytes(request.as_bytes());
+        let response = Worker::handle_stream(&stream, &routes, &config);
+
+        assert_eq!(
+            String::from_utf8_lossy(response.write().as_slice()),
+            format!("HTTP/1.1 304 Not Modified\r\netag: {}\r\n\r\n", etag)
+        );
+
+        let stream = stream_from_bytes(b"GET / HTTP/1.1\r\nIf-Match: \"other\"\r\n\r\n");
+        let response = Worker::handle_stream(&stream, &routes, &config);
+
+        assert_eq!(
+            String::from_utf8_lossy(response.write().as_slice()),
+            format!(
+                "HTTP/1.1 412 Precondition Failed\r\ncontent-length: 0\r\netag: {}\r\n\r\n",
+                etag
+            )
+        );
+    }
+
+    #[test]
+    fn test_handle_stream_cache_control() {
+        let mut route = Route::new(Method::GET, "/", move |_, mut res| {
+            res.set(StatusCode::OK, "Hello World".to_string());
+            res
+        })
+        .unwrap();
+        route.set_cache_control(CacheControl::new().set_no_cache(true));
+        let routes = Arc::new(RwLock::new(vec![route]));
+        let directory = std::env::temp_dir().join("serwer_worker_cache_control");
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(directory.join("hello.txt"), "Hello").unwrap();
+
+        let mut config = Config::new();
+        config.add_mount(
+            Path::from_string("/static").unwrap(),
+            StaticFiles::new(&directory.to_string_lossy()).set_cache_control(
+                CacheControl::new()
+                    .set_public(true)
+                    .set_max_age(Duration::from_secs(3600)),
+            ),
+        );
+
+        let cache_control = |request: &[u8]| {
+            Worker::handle_stream(&stream_from_bytes(request), &routes, &config)
+                .header("Cache-Control")
+        };
+
+        assert_eq!(
+            cache_control(b"GET / HTTP/1.1\r\n\r\n"),
+            Some(String::from("no-cache"))
+        );
+        assert_eq!(
+            cache_control(b"GET /static/hello.txt HTTP/1.1\r\n\r\n"),
+            Some(String::from("public, max-age=3600"))
+        );
+        assert_eq!(
+            cache_control(b"GET /static/missing.txt HTTP/1.1\r\n\r\n"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_handle_stream_cache() {
+        let count = Arc::new(Mutex::new(0));
+        let count_clone = Arc::clone(&count);
+
+        let route = Route::new(Method::GET, "/", move |_, mut res| {
+            let mut count = count_clone.lock().unwrap();
+            *count += 1;
+
+            res.set(StatusCode::OK, format!("Hello {}", count))
+                .set_header("Cache-Control", "max-age=60");
+            res
+        })
+        .unwrap();
+        let routes = Arc::new(RwLock::new(vec![route]));
+        let cache = ResponseCache::new();
+        let mut config = Config::new();
+        config.set_cache(cache.clone());
+
+        let body = |request: &[u8]| {
+            let response = Worker::handle_stream(&stream_from_bytes(request), &routes, &config);
+
+            String::from_utf8(response.body().to_bytes().unwrap()).unwrap()
+        };
+
+        assert_eq!(body(b"GET / HTTP/1.1\r\n\r\n"), "Hello 1");
+        assert_eq!(body(b"GET / HTTP/1.1\r\n\r\n"), "Hello 1");
+        assert_eq!(
+            body(b"GET / HTTP/1.1\r\nCache-Control: no-cache\r\n\r\n"),
+            "Hello 2"
+        );
+        assert_eq!(body(b"GET / HTTP/1.1\r\n\r\n"), "Hello 2");
+
+        cache.invalidate(Method::GET, "/");
+
+        assert_eq!(body(b"GET / HTTP/1.1\r\n\r\n"), "Hello 3");
+        assert_eq!(*count.lock().unwrap(), 3);
+    }
+
+    #[test]
+    fn test_handle_stream_redirects() {
+        let route = Route::new(Method::GET, "/tasks", move |_, mut res| {
+            res.set(StatusCode::OK, "Tasks".to_string());
+            res
+        })
+        .unwrap();
+        let routes = Arc::new(RwLock::new(vec![route]));
+        let mut config = Config::new();
+        config.add_redirect(RedirectRule::Path {
+            from: Path::from_string("/todos").unwrap(),
+            to: String::from("/tasks"),
+            status_code: StatusCode::MovedPermanently,
+        });
+        config.add_redirect(RedirectRule::TrailingSlash(TrailingSlash::Remove));
+
+        let write = |request: &[u8]| {
+            let response = Worker::handle_stream(&stream_from_bytes(request), &routes, &config);
+
+            String::from_utf8_lossy(response.write().as_slice()).to_string()
+        };
+
+        assert_eq!(
+            write(b"GET /todos?page=2 HTTP/1.1\r\n\r\n"),
+            "HTTP/1.1 301 Moved Permanently\r\nlocation: /tasks?page=2\r\n\r\n"
+        );
+        assert_eq!(
+            write(b"GET /tasks/ HTTP/1.1\r\n\r\n"),
+            "HTTP/1.1 308 Permanent Redirect\r\nlocation: /tasks\r\n\r\n"
+        );
+        assert_eq!(
+            write(b"GET /tasks HTTP/1.1\r\n\r\n"),
+            "HTTP/1.1 200 OK\r\ncontent-length: 5\r\n\r\nTasks"
+        );
+    }
+
+    #[test]
+    fn test_handle_stream_normalization() {
+        let route = Route::new(Method::GET, "/user/<id>", move |req, mut res| {
+            res.set(
+                StatusCode::OK,
+                format!("{} {}", req.param("id").unwrap(), req.original_url()),
+            );
+            res
+        })
+        .unwrap();
+        let routes = Arc::new(RwLock::new(vec![route]));
+        let mut config = Config::new();
+        config.set_normalization(NormalizationConfig::new().set_case_folding(true));
+        config.add_rewrite(RewriteRule::new("/u/<id>", "/user/<id>").unwrap());
+
+        let body = |request: &[u8]| {
+            let response = Worker::handle_stream(&stream_from_bytes(request), &routes, &config);
+
+            String::from_utf8(response.body().to_bytes().unwrap()).unwrap()
+        };
+
+        assert_eq!(body(b"GET /user//1 HTTP/1.1\r\n\r\n"), "1 /user/1");
+        assert_eq!(body(b"GET /admin/../USER/2 HTTP/1.1\r\n\r\n"), "2 /user/2");
+        assert_eq!(body(b"GET /u/3?a=b HTTP/1.1\r\n\r\n"), "3 /user/3?a=b");
+    }
+
+    #[test]
+    fn test_handle_stream_cookie_encoding() {
+        let route = Route::new(Method::GET, "/", move |req, mut res| {
+            let prefs = req.cookie("prefs").unwrap();
+            res.set_cookie(
+                "prefs",
+                Cookie::new("prefs", &format!("{}, b", prefs.value())),
+            );
+            res
+        })
+        .unwrap();
+        let routes = Arc::new(RwLock::new(vec![route]));
+        let mut config = Config::new();
+        config.set_cookie_encoding(CookieEncoding::Percent);
+
+        let stream = stream_from_bytes(b"GET / HTTP/1.1\r\nCookie: prefs=%C5%82\r\n\r\n");
+        let response = Worker::handle_stream(&stream, &routes, &config);
+
+        assert_eq!(
+            String::from_utf8_lossy(response.write().as_slice()),
+            "HTTP/1.1 200 OK\r\nset-cookie: prefs=%C5%82%2C%20b\r\n\r\n"
+        );
+    }
+
+    #[cfg(all(feature = "signed", feature = "private"))]
+    #[test]
+    fn test_handle_stream_sealed_cookies() {
+        let set_route = Route::new(Method::GET, "/set", move |_, mut res| {
+            res.set_signed_cookie("user", Cookie::new("user", "Józef").set_http_only(true));
+            res.set_private_cookie("token", Cookie::new("token", "s3cr3t"));
+            res
+        })
+        .unwrap();
+        let get_route = Route::new(Method::GET, "/get", move |req, mut res| {
+            let user = req
+                .signed_cookie("user")
+                .map(|cookie| cookie.value().clone());
+            let token = req
+                .private_cookie("token")
+                .map(|cookie| cookie.value().clone());
+            res.set_body(&format!("{:?} {:?}", user, token));
+            res
+        })
+        .unwrap();
+        let routes = Arc::new(RwLock::new(vec![set_route, get_route]));
+        let mut config = Config::new();
+        config.set_cookie_encoding(CookieEncoding::Percent);
+        config.set_secret_key(SecretKey::from_secret(&[1; 32]).unwrap());
+
+        let stream = stream_from_bytes(b"GET /set HTTP/1.1\r\n\r\n");
+        let response = Worker::handle_stream(&stream, &routes, &config);
+        let head = String::from_utf8(response.write()).unwrap();
+        let cookies: Vec<&str> = head
+            .lines()
+            .filter_map(|line| line.strip_prefix("set-cookie: "))
+            .map(|cookie| cookie.split(';').next().unwrap())
+            .collect();
+
+        assert_eq!(cookies.len(), 2);
+        assert!(cookies[0].starts_with("token="));
+        assert!(!cookies[0].contains("s3cr3t"));
+        assert!(cookies[1].starts_with("user=") && cookies[1].ends_with("J%C3%B3zef"));
+        assert!(head.contains("; HttpOnly\r\n"));
+
+        let get = |config: &Config, cookies: &str| {
+            let request = format!("GET /get HTTP/1.1\r\nCookie: {}\r\n\r\n", cookies);
+            let stream = stream_from_bytes(request.as_bytes());
+            let response = Worker::handle_stream(&stream, &routes, config);
+
+            String::from_utf8(response.body().to_bytes().unwrap()).unwrap()
+        };
+        let cookies = cookies.join("; ");
+
+        assert_eq!(get(&config, &cookies), r#"Some("Józef") Some("s3cr3t")"#);
+        assert_eq!(
+            get(&config, &cookies.replace("J%C3%B3zef", "Jan")),
+            r#"None Some("s3cr3t")"#
+        );
+
+        config.set_secret_key(SecretKey::from_secret(&[2; 32]).unwrap());
+        assert_eq!(get(&config, &cookies), "None None");
+
+        config.add_previous_secret_key(SecretKey::from_secret(&[1; 32]).unwrap());
+        assert_eq!(get(&config, &cookies), r#"Some("Józef") Some("s3cr3t")"#);
+    }
+
+    #[cfg(feature = "signed")]
+    #[test]
+    fn test_handle_stream_signed_cookie_missing_secret_key() {
+        let route = Route::new(Method::GET, "/", move |_, mut res| {
+            res.set_signed_cookie("user", Cookie::new("user", "1"));
+            res
+        })
+        .unwrap();
+        let routes = Arc::new(RwLock::new(vec![route]));
+
+        let stream = stream_from_bytes(b"GET / HTTP/1.1\r\n\r\n");
+        let response = Worker::handle_stream(&stream, &routes, &Config::new());
+
+        assert_eq!(response.status_code(), StatusCode::InternalServerError);
+        assert!(!response.has_cookies());
+    }
 }
@@ -0,0 +1,67 @@
+use crate::{Form, SerwerError};
+use std::collections::HashMap;
+
+pub trait FromForm: Sized {
+    fn from_form(form: Form) -> Result<Self, SerwerError>;
+}
+
+impl FromForm for Form {
+    fn from_form(form: Form) -> Result<Self, SerwerError> {
+        Ok(form)
+    }
+}
+
+impl FromForm for HashMap<String, String> {
+    fn from_form(form: Form) -> Result<Self, SerwerError> {
+        Ok(form.to_map())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Login {
+        username: String,
+        remember: bool,
+    }
+
+    impl FromForm for Login {
+        fn from_form(form: Form) -> Result<Self, SerwerError> {
+            Ok(Self {
+                username: form
+                    .field("username")
+                    .cloned()
+                    .ok_or(SerwerError::MissingFormField(String::from("username")))?,
+                remember: form.field("remember").is_some(),
+            })
+        }
+    }
+
+    #[test]
+    fn test_from_form() {
+        let form = Form::from_string("username=john&remember=on").unwrap();
+
+        assert_eq!(Form::from_form(form.clone()).unwrap(), form);
+        assert_eq!(
+            HashMap::<String, String>::from_form(form.clone())
+                .unwrap()
+                .get("username"),
+            Some(&String::from("john"))
+        );
+
+        let login = Login::from_form(form).unwrap();
+        assert_eq!(login.username, "john");
+        assert!(login.remember);
+    }
+
+    #[test]
+    fn test_from_form_user_type_error() {
+        let form = Form::from_string("remember=on").unwrap();
+
+        assert!(matches!(
+            Login::from_form(form),
+            Err(SerwerError::MissingFormField(error_string)) if &error_string == "username"
+        ));
+    }
+}
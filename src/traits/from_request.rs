@@ -0,0 +1,139 @@
+use crate::{Cookies, Data, Form, Method, Multipart, Request, SerwerError};
+use std::any::{self, TypeId};
+
+pub trait FromRequest: Sized {
+    fn from_request(request: &Request) -> Result<Self, SerwerError>;
+
+    fn required_states() -> Vec<(TypeId, &'static str)> {
+        vec![]
+    }
+}
+
+impl FromRequest for Request {
+    fn from_request(request: &Request) -> Result<Self, SerwerError> {
+        Ok(request.clone())
+    }
+}
+
+impl FromRequest for Method {
+    fn from_request(request: &Request) -> Result<Self, SerwerError> {
+        Ok(request.method())
+    }
+}
+
+impl FromRequest for Cookies {
+    fn from_request(request: &Request) -> Result<Self, SerwerError> {
+        Ok(request.cookies())
+    }
+}
+
+impl FromRequest for Form {
+    fn from_request(request: &Request) -> Result<Self, SerwerError> {
+        request.form()
+    }
+}
+
+impl FromRequest for Multipart {
+    fn from_request(request: &Request) -> Result<Self, SerwerError> {
+        request.multipart()
+    }
+}
+
+impl<T: Send + Sync + 'static> FromRequest for Data<T> {
+    fn from_request(request: &Request) -> Result<Self, SerwerError> {
+        request
+            .states()
+            .state::<T>()
+            .ok_or(SerwerError::MissingState(String::from(
+                any::type_name::<T>(),
+            )))
+    }
+
+    fn required_states() -> Vec<(TypeId, &'static str)> {
+        vec![(TypeId::of::<T>(), any::type_name::<T>())]
+    }
+}
+
+impl<T: FromRequest> FromRequest for Option<T> {
+    fn from_request(request: &Request) -> Result<Self, SerwerError> {
+        Ok(T::from_request(request).ok())
+    }
+}
+
+impl<T: FromRequest> FromRequest for Result<T, SerwerError> {
+    fn from_request(request: &Request) -> Result<Self, SerwerError> {
+        Ok(T::from_request(request))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{request_from_bytes, States};
+
+    #[test]
+    fn test_from_request() {
+        let request = request_from_bytes(
+            b"POST / HTTP/1.1\r\nCookie: id=1\r\nContent-Length: 9\r\n\r\nname=John",
+        )
+        .unwrap();
+
+        assert_eq!(Request::from_request(&request).unwrap(), request);
+        assert_eq!(Method::from_request(&request).unwrap(), Method::POST);
+        assert_eq!(
+            Cookies::from_request(&request)
+                .unwrap()
+                .cookie("id")
+                .unwrap()
+                .value(),
+            "1"
+        );
+        assert_eq!(
+            Form::from_request(&request).unwrap().field("name"),
+            Some(&String::from("John"))
+        );
+    }
+
+    #[test]
+    fn test_from_request_optional() {
+        let request = request_from_bytes(b"GET / HTTP/1.1\r\n\r\n").unwrap();
+
+        assert!(matches!(
+            Multipart::from_request(&request),
+            Err(SerwerError::UnexpectedContentType(_))
+        ));
+        assert!(Option::<Multipart>::from_request(&request)
+            .unwrap()
+            .is_none());
+        assert!(matches!(
+            Result::<Multipart, SerwerError>::from_request(&request),
+            Ok(Err(SerwerError::UnexpectedContentType(_)))
+        ));
+    }
+
+    #[test]
+    fn test_from_request_data() {
+        let mut request = request_from_bytes(b"GET / HTTP/1.1\r\n\r\n").unwrap();
+
+        assert!(matches!(
+            Data::<u32>::from_request(&request),
+            Err(SerwerError::MissingState(error_string)) if &error_string == "u32"
+        ));
+
+        let mut states = States::new();
+        states.set_state(Data::new(42u32));
+        request.set_states(states);
+
+        assert_eq!(*Data::<u32>::from_request(&request).unwrap().read(), 42);
+    }
+
+    #[test]
+    fn test_required_states() {
+        assert_eq!(
+            Data::<u32>::required_states(),
+            vec![(TypeId::of::<u32>(), "u32")]
+        );
+        assert_eq!(Option::<Data<u32>>::required_states(), vec![]);
+        assert_eq!(Request::required_states(), vec![]);
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{FromRequest, IntoResponse, Request, Response};
+use std::any::TypeId;
+
+pub trait Handler<Args>: Send + Sync + 'static {
+    fn call(&self, request: Request, response: Response) -> Response;
+
+    fn required_states() -> Vec<(TypeId, &'static str)>;
+}
+
+macro_rules! impl_handler {
+    ($($arg: ident),*) => {
+        #[allow(non_snake_case, unused_variables, unused_mut)]
+        impl<F, R, $($arg,)*> Handler<($($arg,)*)> for F
+        where
+            F: Fn($($arg),*) -> R + Send + Sync + 'static,
+            R: IntoResponse,
+            $($arg: FromRequest,)*
+        {
+            fn call(&self, request: Request, mut response: Response) -> Response {
+                $(
+                    let $arg = match $arg::from_request(&request) {
+                        Ok(value) => value,
+                        Err(error) => {
+                            response.set_error(&error);
+                            return response;
+                        }
+                    };
+                )*
+
+                (self)($($arg),*).into_response(response)
+            }
+
+            fn required_states() -> Vec<(TypeId, &'static str)> {
+                let mut states = vec![];
+                $(states.extend($arg::required_states());)*
+                states
+            }
+        }
+    };
+}
+
+impl_handler!();
+impl_handler!(A);
+impl_handler!(A, B);
+impl_handler!(A, B, C);
+impl_handler!(A, B, C, D);
+impl_handler!(A, B, C, D, E);
+impl_handler!(A, B, C, D, E, G);
+impl_handler!(A, B, C, D, E, G, H);
+impl_handler!(A, B, C, D, E, G, H, I);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        request_from_bytes, Cookies, Data, Form, Method, SerwerError, StatusCode, Version,
+    };
+
+    fn required_states<Args, H: Handler<Args>>(_: H) -> Vec<(TypeId, &'static str)> {
+        H::required_states()
+    }
+
+    fn call<Args>(handler: impl Handler<Args>, request: &[u8]) -> String {
+        let request = request_from_bytes(request).unwrap();
+        let response = handler.call(request, Response::new(&Version::HTTP_1_1));
+
+        String::from_utf8(response.write()).unwrap()
+    }
+
+    fn hello() -> &'static str {
+        "Hello World"
+    }
+
+    fn greet(method: Method, form: Form, cookies: Cookies) -> String {
+        format!(
+            "{} {} {}",
+            method.to_string(),
+            form.field("name").unwrap(),
+            cookies.cookie("id").unwrap().value()
+        )
+    }
+
+    fn create(form: Result<Form, SerwerError>) -> Result<(StatusCode, String), SerwerError> {
+        let form = form?;
+        let name = form
+            .field("name")
+            .ok_or(SerwerError::MissingFormField(String::from("name")))?;
+
+        Ok((StatusCode::Created, name.clone()))
+    }
+
+    #[test]
+    fn test_call() {
+        assert_eq!(
+            call(hello, b"GET / HTTP/1.1\r\n\r\n"),
+            "HTTP/1.1 200 OK\r\ncontent-length: 11\r\n\r\nHello World"
+        );
+        assert_eq!(
+            call(
+                greet,
+                b"POST / HTTP/1.1\r\nCookie: id=1\r\nContent-Length: 9\r\n\r\nname=John"
+            ),
+            "HTTP/1.1 200 OK\r\ncontent-length: 11\r\n\r\nPOST John 1"
+        );
+        assert_eq!(
+            call(
+                |request: Request| request.original_url(),
+                b"GET /hello HTTP/1.1\r\n\r\n"
+            ),
+            "HTTP/1.1 200 OK\r\ncontent-length: 6\r\n\r\n/hello"
+        );
+    }
+
+    #[test]
+    fn test_call_extraction_error() {
+        assert_eq!(
+            call(
+                greet,
+                b"POST / HTTP/1.1\r\nContent-Type: text/plain\r\nContent-Length: 9\r\n\r\nname=John"
+            ),
+            "HTTP/1.1 415 Unsupported Media Type\r\ncontent-length: 37\r\n\r\nUnexpected content type: \"text/plain\""
+        );
+        assert_eq!(
+            call(
+                create,
+                b"POST / HTTP/1.1\r\nContent-Length: 6\r\n\r\nage=42"
+            ),
+            "HTTP/1.1 422 Unprocessable Entity\r\ncontent-length: 26\r\n\r\nMissing form field: \"name\""
+        );
+        assert_eq!(
+            call(
+                create,
+                b"POST / HTTP/1.1\r\nContent-Length: 9\r\n\r\nname=John"
+            ),
+            "HTTP/1.1 201 Created\r\ncontent-length: 4\r\n\r\nJohn"
+        );
+    }
+
+    #[test]
+    fn test_required_states() {
+        assert_eq!(required_states(hello), vec![]);
+        assert_eq!(
+            required_states(|_: Data<u32>, _: Option<Data<u64>>, _: Data<String>| ()),
+            vec![
+                (TypeId::of::<u32>(), "u32"),
+                (TypeId::of::<String>(), "alloc::string::String")
+            ]
+        );
+    }
+}
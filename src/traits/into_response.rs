@@ -0,0 +1,139 @@
+use crate::{Response, SerwerError, StatusCode};
+
+pub trait IntoResponse {
+    fn into_response(self, response: Response) -> Response;
+}
+
+impl IntoResponse for Response {
+    fn into_response(self, _: Response) -> Response {
+        self
+    }
+}
+
+impl IntoResponse for () {
+    fn into_response(self, response: Response) -> Response {
+        response
+    }
+}
+
+impl IntoResponse for StatusCode {
+    fn into_response(self, mut response: Response) -> Response {
+        response.set_status_code(self);
+        response
+    }
+}
+
+impl IntoResponse for String {
+    fn into_response(self, mut response: Response) -> Response {
+        response.set_body(&self);
+        response
+    }
+}
+
+impl IntoResponse for &str {
+    fn into_response(self, mut response: Response) -> Response {
+        response.set_body(self);
+        response
+    }
+}
+
+impl IntoResponse for Vec<u8> {
+    fn into_response(self, mut response: Response) -> Response {
+        response.set_body_from_bytes(self);
+        response
+    }
+}
+
+impl IntoResponse for SerwerError {
+    fn into_response(self, mut response: Response) -> Response {
+        response.set_error(&self);
+        response
+    }
+}
+
+impl<T: IntoResponse> IntoResponse for (StatusCode, T) {
+    fn into_response(self, response: Response) -> Response {
+        let mut response = self.1.into_response(response);
+        response.set_status_code(self.0);
+        response
+    }
+}
+
+impl<T: IntoResponse, E: IntoResponse> IntoResponse for Result<T, E> {
+    fn into_response(self, response: Response) -> Response {
+        match self {
+            Ok(value) => value.into_response(response),
+            Err(error) => error.into_response(response),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Version;
+
+    fn write(value: impl IntoResponse) -> String {
+        String::from_utf8(
+            value
+                .into_response(Response::new(&Version::HTTP_1_1))
+                .write(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_into_response() {
+        assert_eq!(write(()), "HTTP/1.1 200 OK\r\n\r\n");
+        assert_eq!(
+            write(StatusCode::NoContent),
+            "HTTP/1.1 204 No Content\r\n\r\n"
+        );
+        assert_eq!(
+            write("Hello World"),
+            "HTTP/1.1 200 OK\r\ncontent-length: 11\r\n\r\nHello World"
+        );
+        assert_eq!(
+            write(String::from("Hello World")),
+            "HTTP/1.1 200 OK\r\ncontent-length: 11\r\n\r\nHello World"
+        );
+        assert_eq!(
+            write(b"Hello".to_vec()),
+            "HTTP/1.1 200 OK\r\ncontent-length: 5\r\n\r\nHello"
+        );
+        assert_eq!(
+            write((StatusCode::Created, "Created")),
+            "HTTP/1.1 201 Created\r\ncontent-length: 7\r\n\r\nCreated"
+        );
+
+        let mut response = Response::new(&Version::HTTP_1_1);
+        response.set_status_code(StatusCode::Accepted);
+        assert_eq!(write(response), "HTTP/1.1 202 Accepted\r\n\r\n");
+    }
+
+    #[test]
+    fn test_into_response_result() {
+        assert_eq!(
+            write(Ok::<&str, SerwerError>("Hello")),
+            "HTTP/1.1 200 OK\r\ncontent-length: 5\r\n\r\nHello"
+        );
+        assert_eq!(
+            write(Err::<&str, SerwerError>(SerwerError::MissingFormField(
+                String::from("name")
+            ))),
+            "HTTP/1.1 422 Unprocessable Entity\r\ncontent-length: 26\r\n\r\nMissing form field: \"name\""
+        );
+        assert_eq!(
+            write(
+                std::fs::read("/serwer/missing/file.txt")
+                    .map(|_| "Hello")
+                    .map_err(SerwerError::from)
+            ),
+            "HTTP/1.1 500 Internal Server Error\r\ncontent-length: 21\r\n\r\nInternal Server Error"
+        );
+        assert_eq!(
+            write(Err::<(), StatusCode>(StatusCode::Forbidden)),
+            "HTTP/1.1 403 Forbidden\r\n\r\n"
+        );
+    }
+}
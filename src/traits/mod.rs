@@ -0,0 +1,11 @@
+mod from_form;
+mod from_request;
+mod handler;
+mod into_response;
+mod typed_header;
+
+pub use from_form::*;
+pub use from_request::*;
+pub use handler::*;
+pub use into_response::*;
+pub use typed_header::*;
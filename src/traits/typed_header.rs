@@ -0,0 +1,9 @@
+use crate::SerwerError;
+
+pub trait TypedHeader: Sized {
+    fn name() -> &'static str;
+
+    fn from_value(value: &str) -> Result<Self, SerwerError>;
+
+    fn to_value(&self) -> String;
+}
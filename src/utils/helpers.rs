@@ -1,33 +1,359 @@
 use crate::SerwerError;
 
-pub fn decode(string: &str) -> Result<String, SerwerError> {
+pub fn decode_path(string: &str) -> Result<String, SerwerError> {
+    percent_decode(string, false)
+}
+
+pub fn decode_query(string: &str) -> Result<String, SerwerError> {
+    percent_decode(string, true)
+}
+
+fn percent_decode(string: &str, plus_as_space: bool) -> Result<String, SerwerError> {
+    let bytes = string.as_bytes();
+    let mut result = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+
+    while index < bytes.len() {
+        match bytes[index] {
+            b'%' => {
+                let hex = bytes
+                    .get(index + 1..index + 3)
+                    .filter(|hex| hex.iter().all(u8::is_ascii_hexdigit))
+                    .ok_or(SerwerError::DecodeError(String::from(string)))?;
+
+                result.push(u8::from_str_radix(&String::from_utf8_lossy(hex), 16)?);
+                index += 3;
+            }
+            b'+' if plus_as_space => {
+                result.push(b' ');
+                index += 1;
+            }
+            byte => {
+                result.push(byte);
+                index += 1;
+            }
+        }
+    }
+
+    String::from_utf8(result).map_err(|_| SerwerError::DecodeError(String::from(string)))
+}
+
+const UNRESERVED_CHARACTERS: &str =
+    "-.0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ_abcdefghijklmnopqrstuvwxyz~";
+
+pub fn encode(string: &str) -> String {
     let mut result = String::new();
-    let mut chars = string.chars();
-
-    while let Some(char) = chars.next() {
-        if char == '%' {
-            let mut hex = String::new();
-
-            hex.push(
-                chars
-                    .next()
-                    .ok_or(SerwerError::DecodeError(String::from(string)))?,
-            );
-            hex.push(
-                chars
-                    .next()
-                    .ok_or(SerwerError::DecodeError(String::from(string)))?,
-            );
-
-            let decoded = u8::from_str_radix(&hex, 16)?;
-
-            result.push(decoded as char);
+
+    for byte in string.bytes() {
+        if UNRESERVED_CHARACTERS.contains(byte as char) {
+            result.push(byte as char);
         } else {
+            result.push_str(&format!("%{:02X}", byte));
+        }
+    }
+
+    result
+}
+
+const RESERVED_CHARACTERS: &str = "!#$&'()*+,/:;=?@[]";
+
+pub fn encode_uri(string: &str) -> String {
+    let bytes = string.as_bytes();
+    let mut result = String::new();
+
+    for (index, byte) in bytes.iter().enumerate() {
+        let char = *byte as char;
+        let is_escape = char == '%'
+            && bytes.len() > index + 2
+            && bytes[index + 1].is_ascii_hexdigit()
+            && bytes[index + 2].is_ascii_hexdigit();
+
+        if is_escape || UNRESERVED_CHARACTERS.contains(char) || RESERVED_CHARACTERS.contains(char) {
             result.push(char);
+        } else {
+            result.push_str(&format!("%{:02X}", byte));
+        }
+    }
+
+    result
+}
+
+pub fn escape_html(string: &str) -> String {
+    let mut result = String::new();
+
+    for char in string.chars() {
+        match char {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&#39;"),
+            _ => result.push(char),
+        }
+    }
+
+    result
+}
+
+pub fn split_quoted(string: &str, separator: char) -> Vec<String> {
+    let mut parts = vec![];
+    let mut part = String::new();
+    let mut quoted = false;
+    let mut escaped = false;
+
+    for char in string.chars() {
+        if escaped {
+            escaped = false;
+        } else if quoted && char == '\\' {
+            escaped = true;
+        } else if char == '"' {
+            quoted = !quoted;
+        } else if !quoted && char == separator {
+            parts.push(String::from(part.trim()));
+            part.clear();
+            continue;
+        }
+
+        part.push(char);
+    }
+
+    parts.push(String::from(part.trim()));
+
+    parts
+}
+
+const TOKEN_ALLOWED_CHARACTERS: &str =
+    "!#$%&'*+-.0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ^_`abcdefghijklmnopqrstuvwxyz|~";
+
+pub(crate) fn is_token(string: &str) -> bool {
+    !string.is_empty() && string.chars().all(|c| TOKEN_ALLOWED_CHARACTERS.contains(c))
+}
+
+pub fn unquote(string: &str) -> String {
+    if string.len() >= 2 && string.starts_with('"') && string.ends_with('"') {
+        string[1..string.len() - 1].replace("\\\"", "\"")
+    } else {
+        String::from(string)
+    }
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+pub(crate) fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(FNV_OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(FNV_PRIME)
+    })
+}
+
+const BASE64_ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+const BASE64_URL_ALPHABET: &[u8] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+pub fn base64_encode(bytes: &[u8], url_safe: bool) -> String {
+    let alphabet = if url_safe {
+        BASE64_URL_ALPHABET
+    } else {
+        BASE64_ALPHABET
+    };
+    let mut result = String::new();
+
+    for chunk in bytes.chunks(3) {
+        let buffer = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let indices = [
+            buffer[0] >> 2,
+            ((buffer[0] & 0b11) << 4) | (buffer[1] >> 4),
+            ((buffer[1] & 0b1111) << 2) | (buffer[2] >> 6),
+            buffer[2] & 0b111111,
+        ];
+
+        for index in indices.iter().take(chunk.len() + 1) {
+            result.push(alphabet[*index as usize] as char);
+        }
+
+        if !url_safe {
+            for _ in chunk.len()..3 {
+                result.push('=');
+            }
         }
     }
 
-    let result = result.replace("+", " ");
+    result
+}
+
+pub fn base64_decode(string: &str, url_safe: bool) -> Result<Vec<u8>, SerwerError> {
+    let alphabet = if url_safe {
+        BASE64_URL_ALPHABET
+    } else {
+        BASE64_ALPHABET
+    };
+    let trimmed = string.trim_end_matches('=');
+
+    if trimmed.len() % 4 == 1 || string.len() - trimmed.len() > 2 {
+        return Err(SerwerError::DecodeError(String::from(string)));
+    }
+
+    let mut indices = vec![];
+
+    for char in trimmed.bytes() {
+        let index = alphabet
+            .iter()
+            .position(|c| *c == char)
+            .ok_or(SerwerError::DecodeError(String::from(string)))?;
+        indices.push(index as u8);
+    }
+
+    let mut result = vec![];
+
+    for chunk in indices.chunks(4) {
+        let buffer = [
+            chunk[0],
+            chunk[1],
+            *chunk.get(2).unwrap_or(&0),
+            *chunk.get(3).unwrap_or(&0),
+        ];
+        let bytes = [
+            (buffer[0] << 2) | (buffer[1] >> 4),
+            (buffer[1] << 4) | (buffer[2] >> 2),
+            (buffer[2] << 6) | buffer[3],
+        ];
+
+        result.extend(&bytes[..chunk.len() - 1]);
+    }
 
     Ok(result)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_path() {
+        assert_eq!(decode_path("user").unwrap(), "user");
+        assert_eq!(decode_path("caf%C3%A9").unwrap(), "café");
+        assert_eq!(decode_path("caf%c3%a9").unwrap(), "café");
+        assert_eq!(decode_path("a+b%2Bc").unwrap(), "a+b+c");
+        assert_eq!(decode_path("zażółć").unwrap(), "zażółć");
+    }
+
+    #[test]
+    fn test_decode_query() {
+        assert_eq!(decode_query("John+Doe").unwrap(), "John Doe");
+        assert_eq!(decode_query("1%2B1%3D2").unwrap(), "1+1=2");
+        assert_eq!(decode_query("%E2%82%AC+5").unwrap(), "€ 5");
+    }
+
+    #[test]
+    fn test_decode_invalid() {
+        for string in ["%", "%2", "%hn", "%+1", "%C3", "%FF"] {
+            assert!(matches!(
+                decode_query(string),
+                Err(SerwerError::DecodeError(error_string)) if error_string == string
+            ));
+        }
+    }
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(encode("index.html"), "index.html");
+        assert_eq!(encode("my file.txt"), "my%20file.txt");
+        assert_eq!(encode("a/b?c"), "a%2Fb%3Fc");
+        assert_eq!(encode("zażółć"), "za%C5%BC%C3%B3%C5%82%C4%87");
+    }
+
+    #[test]
+    fn test_encode_uri() {
+        assert_eq!(encode_uri("/docs/index.html"), "/docs/index.html");
+        assert_eq!(
+            encode_uri("https://example.com/a b?q=1&r=[2]#top"),
+            "https://example.com/a%20b?q=1&r=[2]#top"
+        );
+        assert_eq!(encode_uri("/50%25/50%"), "/50%25/50%25");
+        assert_eq!(encode_uri("/zażółć"), "/za%C5%BC%C3%B3%C5%82%C4%87");
+    }
+
+    #[test]
+    fn test_escape_html() {
+        assert_eq!(escape_html("index.html"), "index.html");
+        assert_eq!(
+            escape_html("<a href=\"x\">'&'</a>"),
+            "&lt;a href=&quot;x&quot;&gt;&#39;&amp;&#39;&lt;/a&gt;"
+        );
+    }
+
+    #[test]
+    fn test_split_quoted() {
+        assert_eq!(
+            split_quoted("text/html, text/plain; a=\"x, y\" ,*/*", ','),
+            vec![
+                String::from("text/html"),
+                String::from("text/plain; a=\"x, y\""),
+                String::from("*/*")
+            ]
+        );
+        assert_eq!(split_quoted("", ','), vec![String::from("")]);
+    }
+
+    #[test]
+    fn test_is_token() {
+        assert!(is_token("text"));
+        assert!(is_token("x-custom_1.0~!"));
+        assert!(!is_token(""));
+        assert!(!is_token("a b"));
+        assert!(!is_token("a/b"));
+        assert!(!is_token("zażółć"));
+    }
+
+    #[test]
+    fn test_unquote() {
+        assert_eq!(unquote("\"abc\""), "abc");
+        assert_eq!(unquote("\"a\\\"b\""), "a\"b");
+        assert_eq!(unquote("abc"), "abc");
+        assert_eq!(unquote("\""), "\"");
+    }
+
+    #[test]
+    fn test_fnv1a() {
+        assert_eq!(fnv1a(b""), 0xcbf29ce484222325);
+        assert_eq!(fnv1a(b"a"), 0xaf63dc4c8601ec8c);
+        assert_eq!(fnv1a(b"Hello World"), 0x3d58dee72d4e0c27);
+    }
+
+    #[test]
+    fn test_base64() {
+        assert_eq!(base64_encode(b"", false), "");
+        assert_eq!(base64_encode(b"f", false), "Zg==");
+        assert_eq!(base64_encode(b"fo", false), "Zm8=");
+        assert_eq!(base64_encode(b"foo", false), "Zm9v");
+        assert_eq!(base64_encode(b"user:pass", false), "dXNlcjpwYXNz");
+        assert_eq!(base64_encode(&[251, 255], false), "+/8=");
+        assert_eq!(base64_encode(&[251, 255], true), "-_8");
+
+        assert_eq!(base64_decode("Zg==", false).unwrap(), b"f");
+        assert_eq!(base64_decode("Zm8=", false).unwrap(), b"fo");
+        assert_eq!(base64_decode("dXNlcjpwYXNz", false).unwrap(), b"user:pass");
+        assert_eq!(base64_decode("-_8", true).unwrap(), vec![251, 255]);
+    }
+
+    #[test]
+    fn test_base64_decode_invalid() {
+        assert!(matches!(
+            base64_decode("Zm9v!", false),
+            Err(SerwerError::DecodeError(error_string)) if &error_string == "Zm9v!"
+        ));
+        assert!(matches!(
+            base64_decode("Z", false),
+            Err(SerwerError::DecodeError(error_string)) if &error_string == "Z"
+        ));
+        assert!(matches!(
+            base64_decode("-_8", false),
+            Err(SerwerError::DecodeError(error_string)) if &error_string == "-_8"
+        ));
+    }
+}
@@ -34,18 +34,53 @@ macro_rules! generate_route {
         where
             F: Fn(Request, Response) -> Response + Send + Sync + 'static,
         {
-            if self.route_exists(&$method_enum, path) {
-                custom_panic!("Route already exists")
+            self.add_route($method_enum, path, action, vec![]);
+        }
+    };
+}
+
+macro_rules! generate_typed_header {
+    ($name: ident, $header_name: expr, $value: ty) => {
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct $name(pub $value);
+
+        impl TypedHeader for $name {
+            fn name() -> &'static str {
+                $header_name
             }
 
-            let routes = Arc::clone(&self.routes);
-            let mut routes =
-                unwrap_error!(routes.write(), "Failed to lock routes for write access");
+            fn from_value(value: &str) -> Result<Self, SerwerError> {
+                Ok(Self(<$value>::from_string(value.trim())?))
+            }
 
-            routes.push(unwrap_error!(
-                Route::new($method_enum, path, action),
-                "Error while setting route"
-            ));
+            fn to_value(&self) -> String {
+                self.0.to_string()
+            }
+        }
+    };
+}
+
+macro_rules! generate_quality_header {
+    ($name: ident, $header_name: expr, $value: ty, $parse: expr) => {
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct $name(pub Vec<QualityItem<$value>>);
+
+        impl TypedHeader for $name {
+            fn name() -> &'static str {
+                $header_name
+            }
+
+            fn from_value(value: &str) -> Result<Self, SerwerError> {
+                Ok(Self(QualityItem::list_from_string(value, $parse)?))
+            }
+
+            fn to_value(&self) -> String {
+                self.0
+                    .iter()
+                    .map(|item| item.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            }
         }
     };
 }
@@ -61,7 +96,9 @@ macro_rules! route {
 }
 
 pub(crate) use custom_panic;
+pub(crate) use generate_quality_header;
 pub(crate) use generate_route;
+pub(crate) use generate_typed_header;
 pub(crate) use print_error;
 pub(crate) use unwrap_error;
 pub(crate) use unwrap_none;
@@ -24,7 +24,7 @@ pub fn stream_from_bytes(data: &[u8]) -> TcpStream {
 pub fn request_from_bytes(data: &[u8]) -> Result<Request, SerwerError> {
     let stream = stream_from_bytes(data);
 
-    Request::from_stream(&stream)
+    Request::from_stream(&stream, usize::MAX)
 }
 
 static COUNTER: AtomicU16 = AtomicU16::new(29170);